@@ -60,6 +60,7 @@ fn main() {
     println!("cargo:rerun-if-changed=src/*.rs");
     println!("cargo:rerun-if-changed=src/math/*.rs");
     println!("cargo:rerun-if-changed=src/brep/*.rs");
+    println!("cargo:rerun-if-changed=src/exchange/*.rs");
 }
 
 #[derive(Default)]
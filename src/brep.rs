@@ -29,6 +29,20 @@ macro_rules! shape_impls {
                 }
             }
 
+            impl Clone for $Type {
+                fn clone(&self) -> Self {
+                    unsafe { core::mem::transmute(Shape::clone(self)) }
+                }
+            }
+
+            impl core::fmt::Debug for $Type {
+                fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+                    f.debug_tuple(stringify!($Type))
+                        .field(&self.orientation())
+                        .finish()
+                }
+            }
+
             impl core::ops::Deref for $Type {
                 type Target = Shape;
                 fn deref(&self) -> &Self::Target {
@@ -72,6 +86,21 @@ mod wire;
 mod compsolid;
 mod compound;
 mod ops;
+mod boolean;
+mod fillet;
+mod fillet2d;
+mod mesh;
+mod props;
+mod bounds;
+mod extrema;
+mod classify;
+mod check;
+mod fix;
+mod sewing;
+mod loft;
+mod sweep;
+mod offset;
+mod draft;
 
 pub use edge::*;
 pub use face::*;
@@ -82,12 +111,29 @@ pub use vertex::*;
 pub use wire::*;
 pub use compsolid::*;
 pub use compound::*;
+pub use boolean::*;
+pub use fillet::*;
+pub use fillet2d::*;
+pub use mesh::*;
+pub use props::*;
+pub use bounds::*;
+pub use extrema::*;
+pub use classify::*;
+pub use check::*;
+pub use fix::*;
+pub use sewing::*;
+pub use loft::*;
+pub use sweep::*;
+pub use offset::*;
+pub use draft::*;
 
 use super::math::*;
 
 #[cfg(test)]
 mod test {
     use super::*;
+    use crate::fixtures::*;
+    use core::f64::consts::PI;
 
     #[test]
     fn vertex() {
@@ -170,4 +216,88 @@ mod test {
 
         //assert!(false);
     }
+
+    #[test]
+    fn solid() {
+        let a1 = axis([0.0, 0.0, 0.0]);
+
+        let s1 = Solid::box_(&a1, 1.0, 2.0, 3.0).unwrap();
+        let s2 = Solid::cylinder(&a1, 1.0, 2.0).unwrap();
+        let s3 = Solid::cone_partial(&a1, 1.0, 0.0, 2.0, Angle::new(90.0, AngleUnits::Deg)).unwrap();
+        let s4 = Solid::box_(&a1, 1.0, 0.0, 3.0);
+        let s5 = Solid::sphere_partial(&a1, 1.0, Angle::new(-90.0, AngleUnits::Deg));
+
+        assert_eq!(s1.type_().unwrap(), ShapeType::Solid);
+        assert_eq!(s1.traverse::<Face>().count(), 6);
+        assert_eq!(s2.traverse::<Face>().count(), 3);
+        assert_eq!(s3.traverse::<Face>().count(), 4);
+        if let Err(e) = s4 {
+            assert_eq!(e, SolidError::InvalidDimensions);
+        } else {
+            panic!("Error expected");
+        }
+        if let Err(e) = s5 {
+            assert_eq!(e, SolidError::AngleOutOfRange);
+        } else {
+            panic!("Error expected");
+        }
+    }
+
+    #[test]
+    fn solid_from_shells() {
+        let s1 = cube([0.0, 0.0, 0.0], 3.0);
+        let s2 = cube([1.0, 1.0, 1.0], 1.0);
+        let h1 = s1.traverse::<Shell>().next().unwrap();
+        let h2 = s2.traverse::<Shell>().next().unwrap();
+
+        let s3 = Solid::from_shells(&*h1, [&*h2]).unwrap();
+
+        assert_eq!(s3.traverse::<Shell>().count(), 2);
+        assert!((s3.volume() - 26.0).abs() < 1e-6);
+
+        let s4 = cube([0.0, 0.0, 0.0], 1.0);
+        let s5 = cube([1.0, 0.0, 0.0], 1.0);
+
+        let c1 = CompSolid::from_solids([&s4, &s5]).unwrap();
+        let r1 = c1.traverse::<Solid>().collect::<Vec<_>>();
+
+        assert!(c1.is_valid());
+        assert_eq!(r1.len(), 2);
+        assert_eq!(c1.traverse::<Face>().count(), 11);
+        assert!(r1[0]
+            .traverse::<Face>()
+            .any(|f1| r1[1].traverse::<Face>().any(|f2| f1.is_same(&*f2))));
+        assert!((c1.volume() - 2.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn thread() {
+        let a1 = axis([0.0, 0.0, 0.0]);
+        let h1 = Edge::helix(&a1, 1.0, 1.0, 2.0, false).unwrap();
+        let h2 = Edge::helix(&a1, 1.0, 0.0, 2.0, true);
+
+        assert!((h1.length() - 2.0 * (4.0 * PI * PI + 1.0).sqrt()).abs() < 1e-4);
+        assert!(h2.is_err());
+
+        let p1 = [1.0, 0.0, 0.0];
+        let p2 = [1.2, 0.0, 0.1];
+        let p3 = [1.0, 0.0, 0.2];
+
+        let e1 = Edge::try_from((&p1, &p2)).unwrap();
+        let e2 = Edge::try_from((&p2, &p3)).unwrap();
+        let e3 = Edge::try_from((&p3, &p1)).unwrap();
+        let w1 = Wire::from_edges([&e1, &e2, &e3]).unwrap();
+        let f1 = Face::from_wires([&w1]).unwrap();
+
+        let s1 = Solid::thread(&f1, &a1, 0.5, 2.0).unwrap();
+
+        // The helix passes through the centroid of profile, the triangle of area 0.02
+        let radius = 1.0 + 0.2 / 3.0;
+        let core = PI * radius * radius * 2.0;
+        let ridge = 0.02 * 2.0 * PI * radius * 2.0 / 0.5;
+
+        assert!(s1.is_valid());
+        assert!(s1.volume() > core);
+        assert!(s1.volume() < core + ridge);
+    }
 }
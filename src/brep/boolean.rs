@@ -0,0 +1,335 @@
+use super::{ListOfShape, Shape, Solid};
+use crate::message::Messages;
+use cpp::cpp;
+
+cpp! {{
+    #include <memory>
+
+    #include <string>
+    #include <vector>
+
+    #include <Standard_Failure.hxx>
+
+    #include <TopoDS_Shape.hxx>
+    #include <TopTools_ListOfShape.hxx>
+    #include <Message_Report.hxx>
+
+    #include <BRepAlgoAPI_BooleanOperation.hxx>
+
+    using namespace std;
+}}
+
+enum_impls! {
+    /// The type of boolean operation
+    BooleanOperation {
+        /// The common part of arguments and tools.
+        Common,
+        /// The union of arguments and tools.
+        Fuse,
+        /// The arguments without the parts covered by tools.
+        Cut,
+        /// The tools without the parts covered by arguments.
+        Cut21,
+        /// The intersection edges and vertices of arguments and tools.
+        Section,
+    }
+
+    /// The gluing mode of boolean operation
+    Glue {
+        /// Gluing is turned off.
+        Off,
+        /// The arguments are shifted but their sub-shapes do not interfere.
+        Shift,
+        /// The arguments are coinciding or shared.
+        Full,
+    }
+}
+
+/// Boolean operation error
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct BooleanError {
+    /// The names of errors reported by algorithm
+    pub errors: Vec<String>,
+}
+
+impl Shape {
+    /// Unite this shape with other one
+    pub fn fuse(&self, other: impl AsRef<Shape>) -> Result<Shape, BooleanError> {
+        self.make_boolean(other.as_ref(), BooleanOperation::Fuse)
+    }
+
+    /// Subtract other shape from this one
+    pub fn cut(&self, other: impl AsRef<Shape>) -> Result<Shape, BooleanError> {
+        self.make_boolean(other.as_ref(), BooleanOperation::Cut)
+    }
+
+    /// Get the common part of this shape and other one
+    pub fn common(&self, other: impl AsRef<Shape>) -> Result<Shape, BooleanError> {
+        self.make_boolean(other.as_ref(), BooleanOperation::Common)
+    }
+
+    fn make_boolean(&self, other: &Shape, op: BooleanOperation) -> Result<Shape, BooleanError> {
+        BooleanBuilder::new(op)
+            .argument(self)
+            .tool(other)
+            .build()
+            .map(|output| output.shape)
+    }
+}
+
+/// The result of boolean operation
+pub struct BooleanOutput {
+    /// The resulting shape
+    pub shape: Shape,
+    /// The names of warnings reported by algorithm
+    pub warnings: Vec<String>,
+}
+
+/// Boolean operation with many arguments and tools
+pub struct BooleanBuilder {
+    operation: BooleanOperation,
+    arguments: ListOfShape,
+    tools: ListOfShape,
+    fuzzy_value: f64,
+    non_destructive: bool,
+    glue: Glue,
+    run_parallel: bool,
+}
+
+impl BooleanBuilder {
+    /// Create boolean operation of given type
+    pub fn new(operation: BooleanOperation) -> Self {
+        Self {
+            operation,
+            arguments: ListOfShape::new(),
+            tools: ListOfShape::new(),
+            fuzzy_value: 0.0,
+            non_destructive: false,
+            glue: Glue::Off,
+            run_parallel: false,
+        }
+    }
+
+    /// Add argument shape
+    pub fn argument(mut self, shape: impl AsRef<Shape>) -> Self {
+        self.arguments.push(shape.as_ref());
+        self
+    }
+
+    /// Add argument shapes
+    pub fn arguments<T: AsRef<Shape>>(mut self, shapes: impl IntoIterator<Item = T>) -> Self {
+        for shape in shapes {
+            self.arguments.push(shape.as_ref());
+        }
+        self
+    }
+
+    /// Add tool shape
+    pub fn tool(mut self, shape: impl AsRef<Shape>) -> Self {
+        self.tools.push(shape.as_ref());
+        self
+    }
+
+    /// Add tool shapes
+    pub fn tools<T: AsRef<Shape>>(mut self, shapes: impl IntoIterator<Item = T>) -> Self {
+        for shape in shapes {
+            self.tools.push(shape.as_ref());
+        }
+        self
+    }
+
+    /// Set additional tolerance for interference checking (zero means default precision)
+    pub fn fuzzy_value(mut self, value: f64) -> Self {
+        self.fuzzy_value = value;
+        self
+    }
+
+    /// Keep input shapes untouched
+    pub fn non_destructive(mut self, flag: bool) -> Self {
+        self.non_destructive = flag;
+        self
+    }
+
+    /// Set gluing mode to speed up operation on shapes with coinciding parts
+    pub fn glue(mut self, glue: Glue) -> Self {
+        self.glue = glue;
+        self
+    }
+
+    /// Run operation using multiple threads
+    pub fn run_parallel(mut self, flag: bool) -> Self {
+        self.run_parallel = flag;
+        self
+    }
+
+    /// Perform operation
+    pub fn build(&self) -> Result<BooleanOutput, BooleanError> {
+        if self.arguments.is_empty() {
+            return Err(BooleanError::default());
+        }
+
+        let operation = self.operation;
+        let arguments = &self.arguments;
+        let tools = &self.tools;
+        let fuzzy_value = self.fuzzy_value;
+        let non_destructive = self.non_destructive;
+        let glue = self.glue;
+        let run_parallel = self.run_parallel;
+
+        let mut shape = Shape::default();
+        let r = &mut shape;
+        let mut warnings = Messages::new();
+        let w = &mut warnings;
+        let mut errors = Messages::new();
+        let e = &mut errors;
+
+        let ok = unsafe {
+            cpp!([operation as "BOPAlgo_Operation", arguments as "const unique_ptr<TopTools_ListOfShape>*", tools as "const unique_ptr<TopTools_ListOfShape>*", fuzzy_value as "Standard_Real", non_destructive as "Standard_Boolean", glue as "BOPAlgo_GlueEnum", run_parallel as "Standard_Boolean", r as "unique_ptr<TopoDS_Shape>*", w as "unique_ptr<vector<string>>*", e as "unique_ptr<vector<string>>*"] -> bool as "Standard_Boolean" {
+                try {
+                    BRepAlgoAPI_BooleanOperation b;
+                    b.SetArguments(**arguments);
+                    b.SetTools(**tools);
+                    b.SetOperation(operation);
+                    b.SetFuzzyValue(fuzzy_value);
+                    b.SetNonDestructive(non_destructive);
+                    b.SetGlue(glue);
+                    b.SetRunParallel(run_parallel);
+                    b.Build();
+                    const Message_ListOfAlert& warnings = b.GetReport()->GetAlerts(Message_Warning);
+                    for (Message_ListOfAlert::Iterator it(warnings); it.More(); it.Next()) {
+                        (*w)->push_back(it.Value()->GetMessageKey());
+                    }
+                    const Message_ListOfAlert& errors = b.GetReport()->GetAlerts(Message_Fail);
+                    for (Message_ListOfAlert::Iterator it(errors); it.More(); it.Next()) {
+                        (*e)->push_back(it.Value()->GetMessageKey());
+                    }
+                    if (!b.IsDone() || b.HasErrors()) {
+                        return Standard_False;
+                    }
+                    *r = unique_ptr<TopoDS_Shape>(new TopoDS_Shape(b.Shape()));
+                    return Standard_True;
+                } catch (const Standard_Failure& failure) {
+                    (*e)->push_back(failure.DynamicType()->Name());
+                    return Standard_False;
+                }
+            })
+        };
+
+        if ok {
+            Ok(BooleanOutput {
+                shape,
+                warnings: warnings.to_vec(),
+            })
+        } else {
+            Err(BooleanError {
+                errors: errors.to_vec(),
+            })
+        }
+    }
+}
+
+macro_rules! boolean_impls {
+    ( $($type:ident;)* ) => {
+        $(
+            boolean_impls!(@op $type, BitOr, bitor, fuse);
+            boolean_impls!(@op $type, Sub, sub, cut);
+            boolean_impls!(@op $type, BitAnd, bitand, common);
+        )*
+    };
+
+    (@op $type:ident, $Op:ident, $op:ident, $method:ident) => {
+        impl<T: AsRef<Shape>> core::ops::$Op<T> for $type {
+            type Output = Result<Shape, BooleanError>;
+            fn $op(self, other: T) -> Self::Output {
+                self.$method(other)
+            }
+        }
+
+        impl<T: AsRef<Shape>> core::ops::$Op<T> for &$type {
+            type Output = Result<Shape, BooleanError>;
+            fn $op(self, other: T) -> Self::Output {
+                self.$method(other)
+            }
+        }
+    };
+}
+
+boolean_impls! {
+    Shape;
+    Solid;
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::fixtures::*;
+
+    #[test]
+    fn fuse() {
+        let s1 = cube([0.0, 0.0, 0.0], 1.0);
+        let s2 = cube([0.5, 0.5, 0.5], 1.0);
+
+        let r1 = (&s1 | &s2).unwrap();
+
+        assert_eq!(r1.traverse::<Solid>().count(), 1);
+    }
+
+    #[test]
+    fn cut() {
+        let s1 = cube([0.0, 0.0, 0.0], 1.0);
+        let s2 = cube([0.5, 0.5, 0.5], 1.0);
+
+        let r1 = (&s1 - &s2).unwrap();
+        let r2 = s2.cut(&s1).unwrap();
+
+        assert_eq!(r1.traverse::<Solid>().count(), 1);
+        assert_eq!(r2.traverse::<Solid>().count(), 1);
+    }
+
+    #[test]
+    fn common() {
+        let s1 = cube([0.0, 0.0, 0.0], 1.0);
+        let s2 = cube([0.5, 0.5, 0.5], 1.0);
+        let s3 = cube([2.0, 2.0, 2.0], 1.0);
+
+        let r1 = (&s1 & &s2).unwrap();
+        let r2 = (s1 & s3).unwrap();
+
+        assert_eq!(r1.traverse::<Solid>().count(), 1);
+        assert_eq!(r2.traverse::<Solid>().count(), 0);
+    }
+
+    #[test]
+    fn builder() {
+        let s1 = cube([0.0, 0.0, 0.0], 4.0);
+        let t1 = cube([0.5, 0.5, -1.0], 1.0);
+        let t2 = cube([2.5, 0.5, -1.0], 1.0);
+        let t3 = cube([0.5, 2.5, 3.5], 1.0);
+
+        let r1 = BooleanBuilder::new(BooleanOperation::Cut)
+            .argument(&s1)
+            .tools([&t1, &t2, &t3])
+            .fuzzy_value(1e-5)
+            .run_parallel(true)
+            .build()
+            .unwrap();
+
+        assert_eq!(r1.shape.traverse::<Solid>().count(), 1);
+        assert!(r1.warnings.is_empty());
+
+        let r2 = BooleanBuilder::new(BooleanOperation::Fuse)
+            .tool(&t1)
+            .build();
+
+        assert_eq!(r2.err(), Some(BooleanError::default()));
+
+        let r3 = BooleanBuilder::new(BooleanOperation::Cut)
+            .argument(&s1)
+            .build();
+
+        assert_eq!(
+            r3.err().unwrap().errors,
+            ["BOPAlgo_AlertTooFewArguments".to_string()]
+        );
+    }
+}
@@ -0,0 +1,160 @@
+use super::{Axis1, Axis3, Point, Shape, Vector};
+use cpp::cpp;
+
+cpp! {{
+    #include <memory>
+
+    #include <gp_Pnt.hxx>
+    #include <gp_Vec.hxx>
+    #include <gp_Ax2.hxx>
+
+    #include <TopoDS_Shape.hxx>
+
+    #include <Bnd_Box.hxx>
+    #include <Bnd_OBB.hxx>
+    #include <BRepBndLib.hxx>
+
+    using namespace std;
+}}
+
+/// Axis-aligned bounding box
+#[derive(Clone, Copy, Debug, PartialEq, Default)]
+pub struct BoundingBox {
+    /// The corner with minimal coordinates
+    pub min: Point,
+    /// The corner with maximal coordinates
+    pub max: Point,
+}
+
+impl BoundingBox {
+    /// Get the center of box
+    pub fn center(&self) -> Point {
+        Point::new(
+            (self.min.x + self.max.x) * 0.5,
+            (self.min.y + self.max.y) * 0.5,
+            (self.min.z + self.max.z) * 0.5,
+        )
+    }
+
+    /// Get the sizes of box
+    pub fn size(&self) -> Vector {
+        Vector::new(
+            self.max.x - self.min.x,
+            self.max.y - self.min.y,
+            self.max.z - self.min.z,
+        )
+    }
+}
+
+/// Oriented bounding box
+#[derive(Clone, Copy, Debug, PartialEq, Default)]
+pub struct OrientedBoundingBox {
+    /// The center and directions of box
+    pub axis: Axis3,
+    /// The half sizes of box along X, Y and Z directions of axis
+    pub half_extents: [f64; 3],
+}
+
+impl Shape {
+    /// Compute axis-aligned bounding box
+    ///
+    /// The optimal box is tight but computing it takes more time.
+    /// Returns `None` when shape is empty.
+    pub fn bounding_box(&self, optimal: bool) -> Option<BoundingBox> {
+        let mut bbox = BoundingBox::default();
+        let min = &mut bbox.min;
+        let max = &mut bbox.max;
+
+        let ok = unsafe {
+            cpp!([self as "const unique_ptr<TopoDS_Shape>*", optimal as "Standard_Boolean", min as "gp_Pnt*", max as "gp_Pnt*"] -> bool as "Standard_Boolean" {
+                Bnd_Box box;
+                if (optimal) {
+                    BRepBndLib::AddOptimal(**self, box, Standard_True, Standard_False);
+                } else {
+                    BRepBndLib::Add(**self, box);
+                }
+                if (box.IsVoid()) {
+                    return Standard_False;
+                }
+                *min = box.CornerMin();
+                *max = box.CornerMax();
+                return Standard_True;
+            })
+        };
+
+        ok.then_some(bbox)
+    }
+
+    /// Compute oriented bounding box
+    ///
+    /// The optimal box is tight but computing it takes more time.
+    /// Returns `None` when shape is empty.
+    pub fn oriented_bounding_box(&self, optimal: bool) -> Option<OrientedBoundingBox> {
+        let mut center = Point::default();
+        let mut xdir = Vector::default();
+        let mut ydir = Vector::default();
+        let mut zdir = Vector::default();
+        let mut half_extents = [0.0; 3];
+        let c = &mut center;
+        let x = &mut xdir;
+        let y = &mut ydir;
+        let z = &mut zdir;
+        let h = &mut half_extents;
+
+        let ok = unsafe {
+            cpp!([self as "const unique_ptr<TopoDS_Shape>*", optimal as "Standard_Boolean", c as "gp_Pnt*", x as "gp_Vec*", y as "gp_Vec*", z as "gp_Vec*", h as "Standard_Real*"] -> bool as "Standard_Boolean" {
+                Bnd_OBB box;
+                BRepBndLib::AddOBB(**self, box, Standard_True, optimal, Standard_True);
+                if (box.IsVoid()) {
+                    return Standard_False;
+                }
+                // The directions of box may be left-handed, so Y is derived from Z and X
+                gp_Ax2 frame(gp_Pnt(box.Center()), box.ZDirection(), box.XDirection());
+                *c = frame.Location();
+                *x = gp_Vec(frame.XDirection());
+                *y = gp_Vec(frame.YDirection());
+                *z = gp_Vec(frame.Direction());
+                h[0] = box.XHSize();
+                h[1] = box.YHSize();
+                h[2] = box.ZHSize();
+                return Standard_True;
+            })
+        };
+
+        ok.then(|| OrientedBoundingBox {
+            axis: Axis3::new(Axis1::new(center, zdir), ydir, xdir),
+            half_extents,
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::Solid;
+    use super::*;
+    use crate::fixtures::*;
+
+    #[test]
+    fn bounding_box() {
+        let s1 = Solid::box_(axis([0.0, 0.0, 0.0]), 1.0, 2.0, 3.0).unwrap();
+        let b1 = s1.bounding_box(true).unwrap();
+        let o1 = s1.oriented_bounding_box(true).unwrap();
+        let mut e1 = o1.half_extents;
+        e1.sort_by(f64::total_cmp);
+
+        assert!((b1.size().x - 1.0).abs() < 1e-6);
+        assert!((b1.size().y - 2.0).abs() < 1e-6);
+        assert!((b1.size().z - 3.0).abs() < 1e-6);
+        assert!((e1[0] - 0.5).abs() < 1e-6);
+        assert!((e1[2] - 1.5).abs() < 1e-6);
+
+        let Axis3 { axis, ydir, xdir } = o1.axis;
+        let zdir = axis.dir;
+        let triple = (xdir.y * ydir.z - xdir.z * ydir.y) * zdir.x
+            + (xdir.z * ydir.x - xdir.x * ydir.z) * zdir.y
+            + (xdir.x * ydir.y - xdir.y * ydir.x) * zdir.z;
+
+        assert!((triple - 1.0).abs() < 1e-6);
+        assert!(Shape::default().bounding_box(false).is_none());
+    }
+}
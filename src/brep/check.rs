@@ -0,0 +1,280 @@
+use super::{Shape, TypedShape};
+use cpp::{cpp, cpp_class};
+
+cpp! {{
+    #include <memory>
+
+    #include <utility>
+    #include <vector>
+
+    #include <Standard_Failure.hxx>
+
+    #include <TopoDS_Shape.hxx>
+
+    #include <TopExp.hxx>
+    #include <TopTools_IndexedMapOfShape.hxx>
+
+    #include <BRepCheck_Analyzer.hxx>
+    #include <BRepCheck_Result.hxx>
+    #include <BRepCheck_ListOfStatus.hxx>
+
+    using namespace std;
+
+    typedef vector<pair<TopoDS_Shape, BRepCheck_Status>> CheckProblems;
+
+    static void push_problems(CheckProblems& problems, const TopoDS_Shape& shape, const BRepCheck_ListOfStatus& statuses) {
+        for (BRepCheck_ListOfStatus::Iterator it(statuses); it.More(); it.Next()) {
+            BRepCheck_Status status = it.Value();
+            if (status == BRepCheck_NoError) {
+                continue;
+            }
+            bool found = false;
+            for (const auto& problem : problems) {
+                if (problem.second == status && problem.first.IsSame(shape)) {
+                    found = true;
+                    break;
+                }
+            }
+            if (!found) {
+                problems.push_back(make_pair(shape, status));
+            }
+        }
+    }
+}}
+
+enum_impls! {
+    /// The status of shape validity check
+    CheckStatus {
+        /// The point of vertex does not lie on the curve of edge.
+        InvalidPointOnCurve = 1,
+        /// The point of vertex does not lie on the curve on surface of edge.
+        InvalidPointOnCurveOnSurface,
+        /// The point of vertex does not lie on the surface of face.
+        InvalidPointOnSurface,
+        /// The edge has no 3D curve.
+        No3DCurve,
+        /// The edge has multiple 3D curves.
+        Multiple3DCurve,
+        /// The 3D curve of edge is invalid.
+        Invalid3DCurve,
+        /// The edge has no curve on surface of face.
+        NoCurveOnSurface,
+        /// The curve on surface of edge is invalid.
+        InvalidCurveOnSurface,
+        /// The curve on closed surface of edge is invalid.
+        InvalidCurveOnClosedSurface,
+        /// The same range flag of edge is invalid.
+        InvalidSameRangeFlag,
+        /// The same parameter flag of edge is invalid.
+        InvalidSameParameterFlag,
+        /// The degenerated flag of edge is invalid.
+        InvalidDegeneratedFlag,
+        /// The edge is bound by only one face.
+        FreeEdge,
+        /// The edge is shared by too many faces.
+        InvalidMultiConnexity,
+        /// The parameter range of edge is invalid.
+        InvalidRange,
+        /// The wire has no edges.
+        EmptyWire,
+        /// The wire contains redundant edge.
+        RedundantEdge,
+        /// The wire intersects itself.
+        SelfIntersectingWire,
+        /// The face has no surface.
+        NoSurface,
+        /// The wire of face is invalid.
+        InvalidWire,
+        /// The face contains redundant wire.
+        RedundantWire,
+        /// The wires of face intersect each other.
+        IntersectingWires,
+        /// The wires of face are nested wrongly.
+        InvalidImbricationOfWires,
+        /// The shell has no faces.
+        EmptyShell,
+        /// The shell contains redundant face.
+        RedundantFace,
+        /// The shells of solid are nested wrongly.
+        InvalidImbricationOfShells,
+        /// The shape cannot be oriented.
+        UnorientableShape,
+        /// The shape is not closed.
+        NotClosed,
+        /// The shape is not connected.
+        NotConnected,
+        /// The sub-shape does not belong to the shape.
+        SubshapeNotInShape,
+        /// The orientation of shape is wrong.
+        BadOrientation,
+        /// The orientation of sub-shape is wrong.
+        BadOrientationOfSubshape,
+        /// The polygon on triangulation of edge is invalid.
+        InvalidPolygonOnTriangulation,
+        /// The tolerance value is invalid.
+        InvalidToleranceValue,
+        /// The face contains enclosed region.
+        EnclosedRegion,
+        /// The check itself failed.
+        CheckFail,
+    }
+}
+
+/// The problem found by shape validity check
+#[derive(Clone, Debug)]
+pub struct CheckProblem {
+    /// The sub-shape which has problem
+    pub shape: TypedShape,
+    /// The kind of problem
+    pub status: CheckStatus,
+}
+
+impl Shape {
+    /// Check whether shape is topologically and geometrically valid
+    pub fn is_valid(&self) -> bool {
+        unsafe {
+            cpp!([self as "const unique_ptr<TopoDS_Shape>*"] -> bool as "Standard_Boolean" {
+                try {
+                    BRepCheck_Analyzer analyzer(**self);
+                    return analyzer.IsValid();
+                } catch (const Standard_Failure&) {
+                    return Standard_False;
+                }
+            })
+        }
+    }
+
+    /// Check shape validity and get the list of found problems
+    ///
+    /// The problems which sub-shapes have in the context of their ancestors are reported too.
+    pub fn check(&self) -> Vec<CheckProblem> {
+        let mut problems = CheckProblems::new();
+        let p = &mut problems;
+
+        unsafe {
+            cpp!([self as "const unique_ptr<TopoDS_Shape>*", p as "unique_ptr<CheckProblems>*"] {
+                try {
+                    BRepCheck_Analyzer analyzer(**self);
+                    if (analyzer.IsValid()) {
+                        return;
+                    }
+                    TopTools_IndexedMapOfShape shapes;
+                    TopExp::MapShapes(**self, shapes);
+                    for (Standard_Integer i = 1; i <= shapes.Extent(); i++) {
+                        const TopoDS_Shape& shape = shapes(i);
+                        const Handle(BRepCheck_Result)& result = analyzer.Result(shape);
+                        if (result.IsNull()) {
+                            continue;
+                        }
+                        push_problems(**p, shape, result->Status());
+                        for (result->InitContextIterator(); result->MoreShapeInContext(); result->NextShapeInContext()) {
+                            push_problems(**p, shape, result->StatusOnShape());
+                        }
+                    }
+                } catch (const Standard_Failure&) {
+                    (*p)->push_back(make_pair(**self, BRepCheck_CheckFail));
+                }
+            })
+        };
+
+        (0..problems.len())
+            .filter_map(|index| {
+                let (shape, status) = problems.get(index);
+                Some(CheckProblem {
+                    shape: shape.try_into().ok()?,
+                    status: status.try_into().ok()?,
+                })
+            })
+            .collect()
+    }
+}
+
+cpp_class!(unsafe struct CheckProblems as "unique_ptr<CheckProblems>");
+
+impl CheckProblems {
+    fn new() -> Self {
+        unsafe {
+            cpp!([] -> CheckProblems as "unique_ptr<CheckProblems>" {
+                return unique_ptr<CheckProblems>(new CheckProblems());
+            })
+        }
+    }
+
+    fn len(&self) -> usize {
+        unsafe {
+            cpp!([self as "const unique_ptr<CheckProblems>*"] -> usize as "size_t" {
+                return (*self)->size();
+            })
+        }
+    }
+
+    fn get(&self, index: usize) -> (Shape, u32) {
+        let mut shape = Shape::default();
+        let r = &mut shape;
+        let status = unsafe {
+            cpp!([self as "const unique_ptr<CheckProblems>*", index as "size_t", r as "unique_ptr<TopoDS_Shape>*"] -> u32 as "BRepCheck_Status" {
+                const auto& problem = (**self)[index];
+                *r = unique_ptr<TopoDS_Shape>(new TopoDS_Shape(problem.first));
+                return problem.second;
+            })
+        };
+        (shape, status)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::{Edge, Solid};
+    use super::*;
+    use crate::fixtures::*;
+
+    cpp! {{
+        #include <TopoDS_Shell.hxx>
+        #include <TopoDS_Solid.hxx>
+        #include <TopExp_Explorer.hxx>
+
+        #include <BRep_Builder.hxx>
+    }}
+
+    /// Make solid which shell misses the first face of given solid
+    fn remove_face(solid: &Solid) -> Solid {
+        let mut shape = Shape::default();
+        let r = &mut shape;
+        unsafe {
+            cpp!([solid as "const unique_ptr<TopoDS_Solid>*", r as "unique_ptr<TopoDS_Shape>*"] {
+                BRep_Builder b;
+                TopoDS_Shell shell;
+                b.MakeShell(shell);
+                TopExp_Explorer it(**solid, TopAbs_FACE);
+                for (it.Next(); it.More(); it.Next()) {
+                    b.Add(shell, it.Current());
+                }
+                TopoDS_Solid result;
+                b.MakeSolid(result);
+                b.Add(result, shell);
+                *r = unique_ptr<TopoDS_Shape>(new TopoDS_Shape(result));
+            })
+        };
+        shape.try_into().unwrap()
+    }
+
+    #[test]
+    fn check() {
+        let s1 = cube([0.0, 0.0, 0.0], 1.0);
+        let e1 = Edge::try_from((&[0.0, 0.0, 0.0], &[1.0, 0.0, 0.0])).unwrap();
+
+        assert!(s1.is_valid());
+        assert!(s1.check().is_empty());
+        assert!(e1.is_valid());
+        assert!(e1.check().is_empty());
+
+        let s2 = remove_face(&s1);
+        let problems = s2.check();
+
+        assert!(!s2.is_valid());
+        assert!(problems.iter().any(|problem| {
+            problem.status == CheckStatus::NotClosed
+                && matches!(problem.shape, TypedShape::Shell(_))
+        }));
+    }
+}
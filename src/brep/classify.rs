@@ -0,0 +1,95 @@
+use super::{Point, Solid};
+use cpp::{cpp, cpp_class};
+
+cpp! {{
+    #include <memory>
+
+    #include <gp_Pnt.hxx>
+
+    #include <TopoDS_Solid.hxx>
+
+    #include <BRepClass3d_SolidClassifier.hxx>
+
+    using namespace std;
+}}
+
+enum_impls! {
+    /// The position of point relative to solid
+    PointState {
+        /// The point is inside the solid.
+        Inside,
+        /// The point is outside the solid.
+        Outside,
+        /// The point is on the boundary of the solid within tolerance.
+        OnBoundary,
+        /// The position cannot be determined.
+        Unknown,
+    }
+}
+
+impl Solid {
+    /// Determine the position of point relative to solid
+    pub fn classify(&self, point: impl AsRef<Point>, tolerance: f64) -> PointState {
+        SolidClassifier::new(self).perform(point.as_ref(), tolerance)
+    }
+
+    /// Determine the positions of many points relative to solid
+    ///
+    /// This is faster than classifying each point separately because the solid is prepared only once.
+    pub fn classify_points<T: AsRef<Point>>(
+        &self,
+        points: impl IntoIterator<Item = T>,
+        tolerance: f64,
+    ) -> Vec<PointState> {
+        let mut c = SolidClassifier::new(self);
+        points
+            .into_iter()
+            .map(|point| c.perform(point.as_ref(), tolerance))
+            .collect()
+    }
+}
+
+cpp_class!(unsafe struct SolidClassifier as "unique_ptr<BRepClass3d_SolidClassifier>");
+
+impl SolidClassifier {
+    fn new(solid: &Solid) -> Self {
+        unsafe {
+            cpp!([solid as "const unique_ptr<TopoDS_Solid>*"] -> SolidClassifier as "unique_ptr<BRepClass3d_SolidClassifier>" {
+                return unique_ptr<BRepClass3d_SolidClassifier>(new BRepClass3d_SolidClassifier(**solid));
+            })
+        }
+    }
+
+    fn perform(&mut self, point: &Point, tolerance: f64) -> PointState {
+        unsafe {
+            cpp!([self as "unique_ptr<BRepClass3d_SolidClassifier>*", point as "const gp_Pnt*", tolerance as "Standard_Real"] -> PointState as "TopAbs_State" {
+                (*self)->Perform(*point, tolerance);
+                return (*self)->State();
+            })
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::fixtures::*;
+
+    #[test]
+    fn classify() {
+        let s1 = cube([0.0, 0.0, 0.0], 1.0);
+
+        let p1 = Point::new(0.5, 0.5, 0.5);
+        let p2 = Point::new(1.0, 0.5, 0.5);
+        let p3 = Point::new(-1.0, 0.5, 0.5);
+
+        assert_eq!(s1.classify(p1, 1e-7), PointState::Inside);
+        assert_eq!(s1.classify(p3, 1e-7), PointState::Outside);
+
+        let r1 = s1.classify_points([p1, p2, p3], 1e-7);
+
+        assert_eq!(r1[0], PointState::Inside);
+        assert_eq!(r1[1], PointState::OnBoundary);
+        assert_eq!(r1[2], PointState::Outside);
+    }
+}
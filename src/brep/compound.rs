@@ -1,4 +1,4 @@
-use super::{IsShape, Shape};
+use super::{IsShape, ListOfShape, Shape, TypedShape};
 use core::mem::size_of;
 use cpp::{cpp, cpp_class};
 use static_assertions::const_assert_eq;
@@ -10,6 +10,10 @@ cpp! {{
 
     #include <TopoDS_Shape.hxx>
     #include <TopoDS_Compound.hxx>
+    #include <TopoDS_Iterator.hxx>
+    #include <TopTools_ListOfShape.hxx>
+
+    #include <BRep_Builder.hxx>
 
     using namespace std;
 }}
@@ -24,3 +28,107 @@ const_assert_eq!(size_of::<Compound>(), size_of::<*const u8>());
 shape_impls! {
     Compound;
 }
+
+impl Compound {
+    /// Create compound which groups given shapes
+    pub fn from_shapes<T: AsRef<Shape>>(shapes: impl IntoIterator<Item = T>) -> Self {
+        let mut compound = Self(unsafe {
+            cpp!([] -> CompoundPtr as "unique_ptr<TopoDS_Compound>" {
+                BRep_Builder b;
+                TopoDS_Compound compound;
+                b.MakeCompound(compound);
+                return unique_ptr<TopoDS_Compound>(new TopoDS_Compound(compound));
+            })
+        });
+
+        for shape in shapes {
+            compound.add(shape.as_ref());
+        }
+
+        compound
+    }
+
+    /// Add shape to compound
+    ///
+    /// The compound is copied before modification, so its clones and the shapes which use it are not changed.
+    pub fn push(&mut self, shape: impl AsRef<Shape>) {
+        unsafe {
+            cpp!([self as "unique_ptr<TopoDS_Compound>*"] {
+                BRep_Builder b;
+                TopoDS_Compound compound;
+                b.MakeCompound(compound);
+                for (TopoDS_Iterator it(**self, Standard_False, Standard_False); it.More(); it.Next()) {
+                    b.Add(compound, it.Value());
+                }
+                compound.Location((*self)->Location());
+                compound.Orientation((*self)->Orientation());
+                **self = compound;
+            })
+        }
+        self.add(shape.as_ref());
+    }
+
+    /// Get the direct children of compound
+    pub fn children(&self) -> Vec<TypedShape> {
+        let mut children = ListOfShape::new();
+        let r = &mut children;
+        unsafe {
+            cpp!([self as "const unique_ptr<TopoDS_Compound>*", r as "unique_ptr<TopTools_ListOfShape>*"] {
+                for (TopoDS_Iterator it(**self); it.More(); it.Next()) {
+                    (*r)->Append(it.Value());
+                }
+            })
+        };
+        children.into_vec()
+    }
+
+    fn add(&mut self, shape: &Shape) {
+        unsafe {
+            cpp!([self as "unique_ptr<TopoDS_Compound>*", shape as "const unique_ptr<TopoDS_Shape>*"] {
+                BRep_Builder b;
+                b.Add(**self, **shape);
+            })
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::{Solid, Vertex};
+    use super::*;
+    use crate::fixtures::*;
+    use crate::{Point, Transformation, Vector};
+
+    #[test]
+    fn from_shapes() {
+        let s1 = cube([0.0, 0.0, 0.0], 1.0);
+        let v1 = Vertex::from(&[2.0, 0.0, 0.0]);
+
+        let mut c1 = Compound::from_shapes([&s1]);
+        c1.push(&v1);
+
+        let r1 = c1.children();
+
+        assert_eq!(r1.len(), 2);
+        assert!(matches!(r1[0], TypedShape::Solid(_)));
+        assert!(matches!(r1[1], TypedShape::Vertex(_)));
+        assert_eq!(c1.traverse::<Solid>().count(), 1);
+
+        let mut c2 = c1.clone();
+        c2.push(&v1);
+
+        assert_eq!(c1.children().len(), 2);
+        assert_eq!(c2.children().len(), 3);
+
+        let mut t1 = Transformation::default();
+        t1.set_translation(&Vector::new(5.0, 0.0, 0.0));
+        let mut c3 = Compound::from_shapes([&s1]);
+        c3.set_transformation(&t1);
+        c3.push(Vertex::from(&[10.0, 0.0, 0.0]));
+        let b1 = c3.bounding_box(false).unwrap();
+
+        assert_eq!(c3.transformation().translation_part::<Point>().x, 5.0);
+        assert!((b1.min.x - 5.0).abs() < 1e-3);
+        assert!((b1.max.x - 10.0).abs() < 1e-3);
+    }
+}
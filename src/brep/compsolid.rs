@@ -1,15 +1,27 @@
-use super::{IsShape, Shape};
+use super::{BooleanError, IsShape, ListOfShape, Shape, Solid};
+use crate::message::Messages;
 use core::mem::size_of;
 use cpp::{cpp, cpp_class};
 use static_assertions::const_assert_eq;
 
 cpp! {{
     #include <memory>
+    #include <string>
+    #include <vector>
 
     #include <gp_Pnt.hxx>
 
+    #include <Standard_Failure.hxx>
+    #include <Message_Report.hxx>
+
     #include <TopoDS_Shape.hxx>
     #include <TopoDS_CompSolid.hxx>
+    #include <TopoDS_Solid.hxx>
+    #include <TopExp_Explorer.hxx>
+    #include <TopTools_ListOfShape.hxx>
+
+    #include <BRep_Builder.hxx>
+    #include <BRepAlgoAPI_BuilderAlgo.hxx>
 
     using namespace std;
 }}
@@ -24,3 +36,62 @@ const_assert_eq!(size_of::<CompSolid>(), size_of::<*const u8>());
 shape_impls! {
     CompSolid;
 }
+
+impl CompSolid {
+    /// Create compound solid from solids which share faces
+    ///
+    /// The solids are split by general fuse, so their coincident faces become shared.
+    pub fn from_solids<T: AsRef<Solid>>(
+        solids: impl IntoIterator<Item = T>,
+    ) -> Result<Self, BooleanError> {
+        let mut list = ListOfShape::new();
+        for solid in solids {
+            list.push(solid.as_ref());
+        }
+        let solids = &list;
+
+        let mut shape = Shape::default();
+        let r = &mut shape;
+        let mut errors = Messages::new();
+        let e = &mut errors;
+
+        let ok = unsafe {
+            cpp!([solids as "const unique_ptr<TopTools_ListOfShape>*", r as "unique_ptr<TopoDS_Shape>*", e as "unique_ptr<vector<string>>*"] -> bool as "Standard_Boolean" {
+                try {
+                    BRep_Builder builder;
+                    TopoDS_CompSolid compsolid;
+                    builder.MakeCompSolid(compsolid);
+                    if (!(*solids)->IsEmpty()) {
+                        BRepAlgoAPI_BuilderAlgo b;
+                        b.SetArguments(**solids);
+                        b.SetNonDestructive(Standard_True);
+                        b.Build();
+                        const Message_ListOfAlert& alerts = b.GetReport()->GetAlerts(Message_Fail);
+                        for (Message_ListOfAlert::Iterator it(alerts); it.More(); it.Next()) {
+                            (*e)->push_back(it.Value()->GetMessageKey());
+                        }
+                        if (!b.IsDone() || b.HasErrors()) {
+                            return Standard_False;
+                        }
+                        for (TopExp_Explorer it(b.Shape(), TopAbs_SOLID); it.More(); it.Next()) {
+                            builder.Add(compsolid, it.Current());
+                        }
+                    }
+                    *r = unique_ptr<TopoDS_Shape>(new TopoDS_Shape(compsolid));
+                    return Standard_True;
+                } catch (const Standard_Failure& failure) {
+                    (*e)->push_back(failure.DynamicType()->Name());
+                    return Standard_False;
+                }
+            })
+        };
+
+        if ok {
+            shape.try_into().map_err(|_| BooleanError::default())
+        } else {
+            Err(BooleanError {
+                errors: errors.to_vec(),
+            })
+        }
+    }
+}
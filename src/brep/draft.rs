@@ -0,0 +1,194 @@
+use super::{Angle, AngleUnits, Axis1, Face, Shape, Solid, TypedShape, Vector};
+use cpp::{cpp, cpp_class};
+
+cpp! {{
+    #include <memory>
+
+    #include <Standard_Failure.hxx>
+
+    #include <gp_Vec.hxx>
+    #include <gp_Dir.hxx>
+    #include <gp_Ax1.hxx>
+    #include <gp_Pln.hxx>
+
+    #include <TopoDS_Shape.hxx>
+    #include <TopoDS_Face.hxx>
+    #include <TopoDS_Solid.hxx>
+
+    #include <BRepOffsetAPI_DraftAngle.hxx>
+
+    using namespace std;
+}}
+
+enum_impls! {
+    /// Draft construction status
+    DraftStatus {
+        /// The face cannot be recomputed.
+        FaceRecomputation = 1,
+        /// The edge cannot be recomputed.
+        EdgeRecomputation,
+        /// The vertex cannot be recomputed.
+        VertexRecomputation,
+    }
+}
+
+/// Draft construction error
+#[derive(Clone, Debug)]
+pub struct DraftError {
+    /// The reason of failure, if the algorithm reported it
+    pub status: Option<DraftStatus>,
+    /// The given face which could not be drafted
+    pub face: Option<Face>,
+    /// The sub-shape which caused the failure
+    pub shape: Option<TypedShape>,
+}
+
+impl Solid {
+    /// Tilt the faces by the angle relative to pull direction
+    ///
+    /// The faces keep their intersections with the neutral plane, which is given by its point and normal.
+    pub fn draft<T: AsRef<Face>>(
+        &self,
+        faces: impl IntoIterator<Item = T>,
+        pull_direction: impl AsRef<Vector>,
+        neutral_plane: impl AsRef<Axis1>,
+        angle: impl AsRef<Angle>,
+    ) -> Result<Solid, DraftError> {
+        let direction = pull_direction.as_ref();
+        let plane = neutral_plane.as_ref();
+        let angle = *angle.as_ref().to(AngleUnits::Rad).raw();
+
+        let mut b = DraftAngle::new(self);
+
+        for face in faces {
+            let face = face.as_ref();
+            if !b.add(face, direction, plane, angle) {
+                return Err(b.error(Some(face.clone())));
+            }
+        }
+
+        if b.build() {
+            b.shape().try_into().map_err(|_| b.error(None))
+        } else {
+            Err(b.error(None))
+        }
+    }
+}
+
+cpp_class!(unsafe struct DraftAngle as "unique_ptr<BRepOffsetAPI_DraftAngle>");
+
+impl DraftAngle {
+    fn new(solid: &Solid) -> Self {
+        unsafe {
+            cpp!([solid as "const unique_ptr<TopoDS_Solid>*"] -> DraftAngle as "unique_ptr<BRepOffsetAPI_DraftAngle>" {
+                return unique_ptr<BRepOffsetAPI_DraftAngle>(new BRepOffsetAPI_DraftAngle(**solid));
+            })
+        }
+    }
+
+    fn add(&mut self, face: &Face, direction: &Vector, plane: &Axis1, angle: f64) -> bool {
+        unsafe {
+            cpp!([self as "unique_ptr<BRepOffsetAPI_DraftAngle>*", face as "const unique_ptr<TopoDS_Face>*", direction as "const gp_Vec*", plane as "const gp_Ax1*", angle as "Standard_Real"] -> bool as "Standard_Boolean" {
+                try {
+                    gp_Pln neutral(plane->Location(), plane->Direction());
+                    (*self)->Add(**face, gp_Dir(*direction), angle, neutral);
+                    return (*self)->AddDone();
+                } catch (const Standard_Failure&) {
+                    return Standard_False;
+                }
+            })
+        }
+    }
+
+    fn build(&mut self) -> bool {
+        unsafe {
+            cpp!([self as "unique_ptr<BRepOffsetAPI_DraftAngle>*"] -> bool as "Standard_Boolean" {
+                try {
+                    (*self)->Build();
+                    return (*self)->IsDone();
+                } catch (const Standard_Failure&) {
+                    return Standard_False;
+                }
+            })
+        }
+    }
+
+    fn shape(&self) -> Shape {
+        let mut shape = Shape::default();
+        let r = &mut shape;
+        unsafe {
+            cpp!([self as "const unique_ptr<BRepOffsetAPI_DraftAngle>*", r as "unique_ptr<TopoDS_Shape>*"] {
+                *r = unique_ptr<TopoDS_Shape>(new TopoDS_Shape((*self)->Shape()));
+            })
+        };
+        shape
+    }
+
+    fn error(&self, face: Option<Face>) -> DraftError {
+        let mut shape = Shape::default();
+        let r = &mut shape;
+        let rc = unsafe {
+            cpp!([self as "const unique_ptr<BRepOffsetAPI_DraftAngle>*", r as "unique_ptr<TopoDS_Shape>*"] -> u32 as "Draft_ErrorStatus" {
+                try {
+                    *r = unique_ptr<TopoDS_Shape>(new TopoDS_Shape((*self)->ProblematicShape()));
+                    return (*self)->Status();
+                } catch (const Standard_Failure&) {
+                    return Draft_NoError;
+                }
+            })
+        };
+        DraftError {
+            status: rc.try_into().ok(),
+            face,
+            shape: shape.try_into().ok(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::fixtures::*;
+
+    #[test]
+    fn draft() {
+        let s1 = cube([0.0, 0.0, 0.0], 10.0);
+        let f1 = s1
+            .traverse::<Face>()
+            .filter(|face| {
+                let z = face.surface_properties().center_of_mass.z;
+                z > 1.0 && z < 9.0
+            })
+            .map(|face| Face::clone(&face))
+            .collect::<Vec<_>>();
+
+        let p1: Axis1 = ([0.0, 0.0, 0.0], [0.0, 0.0, 1.0]).into();
+        let d1 = Vector::new(0.0, 0.0, 1.0);
+        let s2 = s1
+            .draft(&f1, d1, p1, Angle::new(5.0, AngleUnits::Deg))
+            .unwrap();
+
+        // The side faces are tilted, so the top face is the square of side 10 ± 2 * 10 * tan(5°)
+        let tilt = 10.0 * 5.0_f64.to_radians().tan();
+        let top = s2
+            .traverse::<Face>()
+            .find(|face| face.surface_properties().center_of_mass.z > 9.0)
+            .unwrap();
+        let side = s2
+            .traverse::<Face>()
+            .find(|face| {
+                let z = face.surface_properties().center_of_mass.z;
+                z > 1.0 && z < 9.0
+            })
+            .unwrap();
+        let b = top.area().sqrt();
+        let size = side.bounding_box(true).unwrap().size();
+
+        assert_eq!(f1.len(), 4);
+        assert_eq!(s2.traverse::<Face>().count(), 6);
+        assert!(s2.is_valid());
+        assert!(((b - 10.0).abs() - 2.0 * tilt).abs() < 1e-6);
+        assert!((s2.volume() - 10.0 / 3.0 * (100.0 + b * b + 10.0 * b)).abs() < 1e-3);
+        assert!((size.x.min(size.y) - tilt).abs() < 1e-3);
+    }
+}
@@ -11,11 +11,20 @@ cpp! {{
     #include <gp_Ax2.hxx>
     #include <gp_Lin.hxx>
     #include <gp_Circ.hxx>
+    #include <gp_Ax3.hxx>
+    #include <gp_Pnt2d.hxx>
+    #include <gp_Dir2d.hxx>
+    #include <gp_Lin2d.hxx>
+
+    #include <Standard_Failure.hxx>
+    #include <Geom_CylindricalSurface.hxx>
+    #include <Geom2d_Line.hxx>
 
     #include <TopoDS_Shape.hxx>
     #include <TopoDS_Vertex.hxx>
     #include <TopoDS_Edge.hxx>
 
+    #include <BRepLib.hxx>
     #include <BRepBuilderAPI_MakeEdge.hxx>
 
     using namespace std;
@@ -137,6 +146,61 @@ impl Edge {
             Ok(Self(e))
         }
     }
+
+    /// Make helix around the axis starting from its X direction
+    ///
+    /// The pitch is the distance between turns along the axis.
+    pub fn helix(
+        axis: impl AsRef<Axis3>,
+        radius: f64,
+        pitch: f64,
+        height: f64,
+        left_handed: bool,
+    ) -> Result<Self, EdgeError> {
+        if [radius, pitch, height]
+            .iter()
+            .any(|value| !(*value > 0.0 && value.is_finite()))
+        {
+            return Err(EdgeError::ParameterOutOfRange);
+        }
+        Self::make_helix(axis.as_ref(), radius, pitch, height, left_handed)
+    }
+
+    fn make_helix(
+        axis: &Axis3,
+        radius: f64,
+        pitch: f64,
+        height: f64,
+        left_handed: bool,
+    ) -> Result<Self, EdgeError> {
+        let mut e = EdgePtr::default();
+        let r = &mut e;
+        let rc = unsafe {
+            cpp!([axis as "const gp_Ax2*", radius as "Standard_Real", pitch as "Standard_Real", height as "Standard_Real", left_handed as "Standard_Boolean", r as "unique_ptr<TopoDS_Edge>*"] -> u32 as "BRepBuilderAPI_EdgeError" {
+                try {
+                    Handle(Geom_CylindricalSurface) surface = new Geom_CylindricalSurface(gp_Ax3(*axis), radius);
+                    Standard_Real turn = left_handed ? -2.0 * M_PI : 2.0 * M_PI;
+                    Handle(Geom2d_Line) line = new Geom2d_Line(gp_Pnt2d(0.0, 0.0), gp_Dir2d(turn, pitch));
+                    Standard_Real length = height / pitch * sqrt(turn * turn + pitch * pitch);
+                    BRepBuilderAPI_MakeEdge b(line, surface, 0.0, length);
+                    auto rc = b.Error();
+                    if (rc == BRepBuilderAPI_EdgeDone) {
+                        TopoDS_Edge edge = b.Edge();
+                        BRepLib::BuildCurves3d(edge);
+                        *r = unique_ptr<TopoDS_Edge>(new TopoDS_Edge(edge));
+                    }
+                    return rc;
+                } catch (const Standard_Failure&) {
+                    return BRepBuilderAPI_ParameterOutOfRange;
+                }
+            })
+        };
+        if let Ok(err) = rc.try_into() {
+            Err(err)
+        } else {
+            Ok(Self(e))
+        }
+    }
 }
 
 impl TryFrom<(&Vertex, &Vertex)> for Edge {
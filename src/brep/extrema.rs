@@ -0,0 +1,190 @@
+use super::{Edge, Face, Point, Shape, Vertex};
+use cpp::{cpp, cpp_class};
+
+cpp! {{
+    #include <memory>
+
+    #include <Standard_Failure.hxx>
+
+    #include <gp_Pnt.hxx>
+
+    #include <TopoDS_Shape.hxx>
+
+    #include <BRepExtrema_DistShapeShape.hxx>
+
+    using namespace std;
+}}
+
+/// Distance computation error
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct DistanceError;
+
+/// The sub-shape on which the closest point lies
+#[derive(Clone, Debug)]
+pub enum DistanceSupport {
+    /// The point is a vertex.
+    Vertex(Vertex),
+    /// The point lies on an edge at given curve parameter.
+    Edge(Edge, f64),
+    /// The point lies on a face at given surface parameters.
+    Face(Face, f64, f64),
+}
+
+/// The pair of closest points
+#[derive(Clone, Debug)]
+pub struct DistanceSolution {
+    /// The point on the first shape
+    pub point1: Point,
+    /// The point on the second shape
+    pub point2: Point,
+    /// The sub-shape of the first shape which contains the point
+    pub support1: DistanceSupport,
+    /// The sub-shape of the second shape which contains the point
+    pub support2: DistanceSupport,
+}
+
+/// The minimal distance between shapes
+#[derive(Clone, Debug)]
+pub struct Distance {
+    /// The value of distance
+    pub value: f64,
+    /// The pairs of points where the distance is reached
+    pub solutions: Vec<DistanceSolution>,
+}
+
+impl Shape {
+    /// Compute the minimal distance to other shape
+    pub fn distance_to(&self, other: impl AsRef<Shape>) -> Result<Distance, DistanceError> {
+        let b = DistShapeShape::new(self, other.as_ref()).ok_or(DistanceError)?;
+
+        let value = b.value();
+        let solutions = (1..=b.len()).map(|index| b.solution(index)).collect();
+
+        Ok(Distance { value, solutions })
+    }
+}
+
+cpp_class!(unsafe struct DistShapeShape as "unique_ptr<BRepExtrema_DistShapeShape>");
+
+impl DistShapeShape {
+    fn new(shape1: &Shape, shape2: &Shape) -> Option<Self> {
+        let mut b = unsafe {
+            cpp!([] -> DistShapeShape as "unique_ptr<BRepExtrema_DistShapeShape>" {
+                return unique_ptr<BRepExtrema_DistShapeShape>(new BRepExtrema_DistShapeShape());
+            })
+        };
+
+        let ok = unsafe {
+            let b = &mut b;
+            cpp!([b as "unique_ptr<BRepExtrema_DistShapeShape>*", shape1 as "const unique_ptr<TopoDS_Shape>*", shape2 as "const unique_ptr<TopoDS_Shape>*"] -> bool as "Standard_Boolean" {
+                try {
+                    (*b)->LoadS1(**shape1);
+                    (*b)->LoadS2(**shape2);
+                    return (*b)->Perform() && (*b)->IsDone();
+                } catch (const Standard_Failure&) {
+                    return Standard_False;
+                }
+            })
+        };
+
+        ok.then_some(b)
+    }
+
+    fn value(&self) -> f64 {
+        unsafe {
+            cpp!([self as "const unique_ptr<BRepExtrema_DistShapeShape>*"] -> f64 as "Standard_Real" {
+                return (*self)->Value();
+            })
+        }
+    }
+
+    fn len(&self) -> i32 {
+        unsafe {
+            cpp!([self as "const unique_ptr<BRepExtrema_DistShapeShape>*"] -> i32 as "Standard_Integer" {
+                return (*self)->NbSolution();
+            })
+        }
+    }
+
+    fn solution(&self, index: i32) -> DistanceSolution {
+        let mut point1 = Point::default();
+        let mut point2 = Point::default();
+        let p1 = &mut point1;
+        let p2 = &mut point2;
+
+        unsafe {
+            cpp!([self as "const unique_ptr<BRepExtrema_DistShapeShape>*", index as "Standard_Integer", p1 as "gp_Pnt*", p2 as "gp_Pnt*"] {
+                *p1 = (*self)->PointOnShape1(index);
+                *p2 = (*self)->PointOnShape2(index);
+            })
+        };
+
+        DistanceSolution {
+            point1,
+            point2,
+            support1: self.support(index, true),
+            support2: self.support(index, false),
+        }
+    }
+
+    fn support(&self, index: i32, first: bool) -> DistanceSupport {
+        let mut shape = Shape::default();
+        let mut params = [0.0; 2];
+        let r = &mut shape;
+        let p = &mut params;
+
+        let rc = unsafe {
+            cpp!([self as "const unique_ptr<BRepExtrema_DistShapeShape>*", index as "Standard_Integer", first as "Standard_Boolean", r as "unique_ptr<TopoDS_Shape>*", p as "Standard_Real*"] -> u32 as "BRepExtrema_SupportType" {
+                BRepExtrema_SupportType type = first ? (*self)->SupportTypeShape1(index) : (*self)->SupportTypeShape2(index);
+                *r = unique_ptr<TopoDS_Shape>(new TopoDS_Shape(first ? (*self)->SupportOnShape1(index) : (*self)->SupportOnShape2(index)));
+                switch (type) {
+                case BRepExtrema_IsOnEdge:
+                    if (first) {
+                        (*self)->ParOnEdgeS1(index, p[0]);
+                    } else {
+                        (*self)->ParOnEdgeS2(index, p[0]);
+                    }
+                    break;
+                case BRepExtrema_IsInFace:
+                    if (first) {
+                        (*self)->ParOnFaceS1(index, p[0], p[1]);
+                    } else {
+                        (*self)->ParOnFaceS2(index, p[0], p[1]);
+                    }
+                    break;
+                default:
+                    break;
+                }
+                return type;
+            })
+        };
+
+        match rc {
+            0 => DistanceSupport::Vertex(shape.try_into().unwrap()),
+            1 => DistanceSupport::Edge(shape.try_into().unwrap(), params[0]),
+            _ => DistanceSupport::Face(shape.try_into().unwrap(), params[0], params[1]),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::fixtures::*;
+
+    #[test]
+    fn distance_to() {
+        let s1 = cube([0.0, 0.0, 0.0], 1.0);
+        let s2 = cube([2.0, 0.0, 0.0], 1.0);
+        let v1 = Vertex::from(&[3.0, 3.0, 0.0]);
+
+        let d1 = s1.distance_to(&s2).unwrap();
+        let d2 = s2.distance_to(&v1).unwrap();
+
+        assert!((d1.value - 1.0).abs() < 1e-6);
+        assert!(!d1.solutions.is_empty());
+        assert!((d2.value - 2.0).abs() < 1e-6);
+        let r1 = &d2.solutions[0];
+        assert!(matches!(r1.support2, DistanceSupport::Vertex(_)));
+    }
+}
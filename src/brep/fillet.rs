@@ -0,0 +1,349 @@
+use super::{Edge, Shape, Solid};
+use cpp::{cpp, cpp_class};
+
+cpp! {{
+    #include <memory>
+
+    #include <Standard_Failure.hxx>
+
+    #include <TopoDS.hxx>
+    #include <TopoDS_Shape.hxx>
+    #include <TopoDS_Edge.hxx>
+    #include <TopoDS_Face.hxx>
+    #include <TopoDS_Solid.hxx>
+
+    #include <TopExp.hxx>
+    #include <TopTools_IndexedDataMapOfShapeListOfShape.hxx>
+
+    #include <BRepFilletAPI_MakeFillet.hxx>
+    #include <BRepFilletAPI_MakeChamfer.hxx>
+
+    using namespace std;
+
+    struct ChamferBuilder {
+        BRepFilletAPI_MakeChamfer builder;
+        TopTools_IndexedDataMapOfShapeListOfShape edge_faces;
+
+        ChamferBuilder(const TopoDS_Shape& shape): builder(shape) {
+            TopExp::MapShapesAndAncestors(shape, TopAbs_EDGE, TopAbs_FACE, edge_faces);
+        }
+    };
+}}
+
+enum_impls! {
+    /// Fillet computation status
+    FilletStatus {
+        /// The computation failed for unknown reason.
+        Error = 1,
+        /// The walking along contour failed.
+        WalkingFailure,
+        /// The start solution cannot be found.
+        StartsolFailure,
+        /// The fillet surface is twisted.
+        TwistedSurface,
+    }
+}
+
+/// Fillet or chamfer construction error
+#[derive(Debug)]
+pub struct FilletError {
+    /// The reason of failure
+    pub status: FilletStatus,
+    /// The edges which could not be processed
+    pub edges: Vec<Edge>,
+}
+
+impl FilletError {
+    fn new(status: FilletStatus, edges: Vec<Edge>) -> Self {
+        Self { status, edges }
+    }
+}
+
+impl Solid {
+    /// Round the edges with constant radius
+    pub fn fillet<T: AsRef<Edge>>(
+        &self,
+        edges: impl IntoIterator<Item = T>,
+        radius: f64,
+    ) -> Result<Solid, FilletError> {
+        self.make_fillet(edges, radius, radius)
+    }
+
+    /// Round the edges with radius which linearly changes along each contour
+    pub fn fillet_variable<T: AsRef<Edge>>(
+        &self,
+        edges: impl IntoIterator<Item = T>,
+        radius1: f64,
+        radius2: f64,
+    ) -> Result<Solid, FilletError> {
+        self.make_fillet(edges, radius1, radius2)
+    }
+
+    fn make_fillet<T: AsRef<Edge>>(
+        &self,
+        edges: impl IntoIterator<Item = T>,
+        radius1: f64,
+        radius2: f64,
+    ) -> Result<Solid, FilletError> {
+        let mut b = MakeFillet::new(self);
+        let mut added = Vec::new();
+
+        for edge in edges {
+            let edge = edge.as_ref();
+            if !b.add(edge, radius1, radius2) {
+                return Err(FilletError::new(FilletStatus::Error, vec![edge.clone()]));
+            }
+            added.push(edge.clone());
+        }
+
+        if b.build() {
+            b.shape()
+                .try_into()
+                .map_err(|_| FilletError::new(FilletStatus::Error, added))
+        } else {
+            let mut error = b.error();
+            if error.edges.is_empty() {
+                error.edges = added;
+            }
+            Err(error)
+        }
+    }
+
+    /// Bevel the edges with equal distances on both adjacent faces
+    pub fn chamfer<T: AsRef<Edge>>(
+        &self,
+        edges: impl IntoIterator<Item = T>,
+        distance: f64,
+    ) -> Result<Solid, FilletError> {
+        self.make_chamfer(edges, distance, distance)
+    }
+
+    /// Bevel the edges with different distances
+    ///
+    /// The first distance is measured on the first face adjacent to the edge.
+    pub fn chamfer_asymmetric<T: AsRef<Edge>>(
+        &self,
+        edges: impl IntoIterator<Item = T>,
+        distance1: f64,
+        distance2: f64,
+    ) -> Result<Solid, FilletError> {
+        self.make_chamfer(edges, distance1, distance2)
+    }
+
+    fn make_chamfer<T: AsRef<Edge>>(
+        &self,
+        edges: impl IntoIterator<Item = T>,
+        distance1: f64,
+        distance2: f64,
+    ) -> Result<Solid, FilletError> {
+        let mut b = MakeChamfer::new(self);
+        let mut added = Vec::new();
+
+        for edge in edges {
+            let edge = edge.as_ref();
+            if !b.add(edge, distance1, distance2) {
+                return Err(FilletError::new(FilletStatus::Error, vec![edge.clone()]));
+            }
+            added.push(edge.clone());
+        }
+
+        // The chamfer builder does not report faulty contours so all edges are suspected
+        if b.build() {
+            b.shape()
+                .try_into()
+                .map_err(|_| FilletError::new(FilletStatus::Error, added))
+        } else {
+            Err(FilletError::new(FilletStatus::Error, added))
+        }
+    }
+}
+
+cpp_class!(unsafe struct MakeFillet as "unique_ptr<BRepFilletAPI_MakeFillet>");
+
+impl MakeFillet {
+    fn new(solid: &Solid) -> Self {
+        unsafe {
+            cpp!([solid as "const unique_ptr<TopoDS_Solid>*"] -> MakeFillet as "unique_ptr<BRepFilletAPI_MakeFillet>" {
+                return unique_ptr<BRepFilletAPI_MakeFillet>(new BRepFilletAPI_MakeFillet(**solid));
+            })
+        }
+    }
+
+    fn add(&mut self, edge: &Edge, radius1: f64, radius2: f64) -> bool {
+        unsafe {
+            cpp!([self as "unique_ptr<BRepFilletAPI_MakeFillet>*", edge as "const unique_ptr<TopoDS_Edge>*", radius1 as "Standard_Real", radius2 as "Standard_Real"] -> bool as "Standard_Boolean" {
+                try {
+                    (*self)->Add(radius1, radius2, **edge);
+                    return (*self)->Contour(**edge) != 0;
+                } catch (const Standard_Failure&) {
+                    return Standard_False;
+                }
+            })
+        }
+    }
+
+    fn build(&mut self) -> bool {
+        unsafe {
+            cpp!([self as "unique_ptr<BRepFilletAPI_MakeFillet>*"] -> bool as "Standard_Boolean" {
+                try {
+                    (*self)->Build();
+                    return (*self)->IsDone();
+                } catch (const Standard_Failure&) {
+                    return Standard_False;
+                }
+            })
+        }
+    }
+
+    fn shape(&self) -> Shape {
+        let mut shape = Shape::default();
+        let r = &mut shape;
+        unsafe {
+            cpp!([self as "const unique_ptr<BRepFilletAPI_MakeFillet>*", r as "unique_ptr<TopoDS_Shape>*"] {
+                *r = unique_ptr<TopoDS_Shape>(new TopoDS_Shape((*self)->Shape()));
+            })
+        };
+        shape
+    }
+
+    fn error(&self) -> FilletError {
+        let mut status = None;
+        let mut edges = Vec::new();
+
+        let count = unsafe {
+            cpp!([self as "const unique_ptr<BRepFilletAPI_MakeFillet>*"] -> i32 as "Standard_Integer" {
+                return (*self)->NbFaultyContours();
+            })
+        };
+
+        for index in 1..=count {
+            let contour = unsafe {
+                cpp!([self as "const unique_ptr<BRepFilletAPI_MakeFillet>*", index as "Standard_Integer"] -> i32 as "Standard_Integer" {
+                    return (*self)->FaultyContour(index);
+                })
+            };
+
+            if status.is_none() {
+                let rc = unsafe {
+                    cpp!([self as "const unique_ptr<BRepFilletAPI_MakeFillet>*", contour as "Standard_Integer"] -> u32 as "ChFiDS_ErrorStatus" {
+                        return (*self)->StripeStatus(contour);
+                    })
+                };
+                status = rc.try_into().ok();
+            }
+
+            let count = unsafe {
+                cpp!([self as "const unique_ptr<BRepFilletAPI_MakeFillet>*", contour as "Standard_Integer"] -> i32 as "Standard_Integer" {
+                    return (*self)->NbEdges(contour);
+                })
+            };
+
+            for index in 1..=count {
+                let mut shape = Shape::default();
+                let r = &mut shape;
+                unsafe {
+                    cpp!([self as "const unique_ptr<BRepFilletAPI_MakeFillet>*", contour as "Standard_Integer", index as "Standard_Integer", r as "unique_ptr<TopoDS_Shape>*"] {
+                        *r = unique_ptr<TopoDS_Shape>(new TopoDS_Shape((*self)->Edge(contour, index)));
+                    })
+                };
+                if let Ok(edge) = shape.try_into() {
+                    edges.push(edge);
+                }
+            }
+        }
+
+        FilletError::new(status.unwrap_or(FilletStatus::Error), edges)
+    }
+}
+
+cpp_class!(unsafe struct MakeChamfer as "unique_ptr<ChamferBuilder>");
+
+impl MakeChamfer {
+    fn new(solid: &Solid) -> Self {
+        unsafe {
+            cpp!([solid as "const unique_ptr<TopoDS_Solid>*"] -> MakeChamfer as "unique_ptr<ChamferBuilder>" {
+                return unique_ptr<ChamferBuilder>(new ChamferBuilder(**solid));
+            })
+        }
+    }
+
+    fn add(&mut self, edge: &Edge, distance1: f64, distance2: f64) -> bool {
+        unsafe {
+            cpp!([self as "unique_ptr<ChamferBuilder>*", edge as "const unique_ptr<TopoDS_Edge>*", distance1 as "Standard_Real", distance2 as "Standard_Real"] -> bool as "Standard_Boolean" {
+                const TopTools_ListOfShape* faces = (*self)->edge_faces.Seek(**edge);
+                if (faces == NULL || faces->IsEmpty()) {
+                    return Standard_False;
+                }
+                try {
+                    (*self)->builder.Add(distance1, distance2, **edge, TopoDS::Face(faces->First()));
+                    return (*self)->builder.Contour(**edge) != 0;
+                } catch (const Standard_Failure&) {
+                    return Standard_False;
+                }
+            })
+        }
+    }
+
+    fn build(&mut self) -> bool {
+        unsafe {
+            cpp!([self as "unique_ptr<ChamferBuilder>*"] -> bool as "Standard_Boolean" {
+                try {
+                    (*self)->builder.Build();
+                    return (*self)->builder.IsDone();
+                } catch (const Standard_Failure&) {
+                    return Standard_False;
+                }
+            })
+        }
+    }
+
+    fn shape(&self) -> Shape {
+        let mut shape = Shape::default();
+        let r = &mut shape;
+        unsafe {
+            cpp!([self as "const unique_ptr<ChamferBuilder>*", r as "unique_ptr<TopoDS_Shape>*"] {
+                *r = unique_ptr<TopoDS_Shape>(new TopoDS_Shape((*self)->builder.Shape()));
+            })
+        };
+        shape
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::Face;
+    use super::*;
+    use crate::fixtures::*;
+
+    #[test]
+    fn fillet() {
+        let s1 = cube([0.0, 0.0, 0.0], 10.0);
+        let s2 = s1.fillet(s1.traverse::<Edge>(), 1.0).unwrap();
+        let e1 = s1.traverse::<Edge>().next().unwrap();
+        let s3 = s1.fillet([&*e1], 20.0);
+
+        assert_eq!(s2.traverse::<Face>().count(), 26);
+
+        let r1 = s3.unwrap_err();
+
+        assert!(!r1.edges.is_empty());
+        assert!(r1.edges.iter().any(|edge| edge.is_same(&*e1)));
+    }
+
+    #[test]
+    fn chamfer() {
+        let s1 = cube([0.0, 0.0, 0.0], 10.0);
+        let s2 = s1.chamfer(s1.traverse::<Edge>().take(1), 1.0).unwrap();
+        let s3 = s1
+            .chamfer_asymmetric(s1.traverse::<Edge>().take(1), 1.0, 2.0)
+            .unwrap();
+
+        assert_eq!(s2.traverse::<Face>().count(), 7);
+        assert_eq!(s3.traverse::<Face>().count(), 7);
+
+        let e1 = s1.traverse::<Edge>().next().unwrap();
+        let r1 = s1.chamfer([&*e1], 20.0).unwrap_err();
+
+        assert!(r1.edges.iter().any(|edge| edge.is_same(&*e1)));
+    }
+}
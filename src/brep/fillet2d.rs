@@ -0,0 +1,262 @@
+use super::{Edge, Face, Shape, Vertex, Wire};
+use cpp::cpp;
+
+cpp! {{
+    #include <memory>
+
+    #include <Standard_Failure.hxx>
+
+    #include <TopoDS.hxx>
+    #include <TopoDS_Shape.hxx>
+    #include <TopoDS_Vertex.hxx>
+    #include <TopoDS_Edge.hxx>
+    #include <TopoDS_Wire.hxx>
+    #include <TopoDS_Face.hxx>
+
+    #include <TopExp.hxx>
+    #include <TopTools_IndexedDataMapOfShapeListOfShape.hxx>
+
+    #include <BRep_Tool.hxx>
+    #include <BRepTools.hxx>
+    #include <BRepBuilderAPI_MakeFace.hxx>
+    #include <BRepFilletAPI_MakeFillet2d.hxx>
+
+    using namespace std;
+}}
+
+/// Planar fillet or chamfer construction error
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Fillet2dError {
+    /// The face is not planar.
+    NotPlanar,
+    /// The face is null.
+    NoFace,
+    /// The two faces used for initialization are uncorrelated.
+    InitialisationError,
+    /// The parameters given are outside the bounds of the face or are incorrect.
+    ParametersError,
+    /// The fillet or chamfer cannot be built because of geometric reasons.
+    ComputationError,
+    /// The given vertex or edges are not connected to the face.
+    ConnexionError,
+    /// The edges are tangent at the given vertex.
+    TangencyError,
+    /// The first edge is degenerated by the operation.
+    FirstEdgeDegenerated,
+    /// The last edge is degenerated by the operation.
+    LastEdgeDegenerated,
+    /// Both edges are degenerated by the operation.
+    BothEdgesDegenerated,
+    /// The given vertex or edges are already modified by the operation.
+    NotAuthorized,
+    /// The wire is not closed.
+    OpenWire,
+}
+
+impl Fillet2dError {
+    /// Map raw `ChFi2d_ConstructionError` status, `None` means done
+    fn from_status(rc: u32) -> Option<Self> {
+        Some(match rc {
+            0 => Self::NotPlanar,
+            1 => Self::NoFace,
+            2 => Self::InitialisationError,
+            3 => Self::ParametersError,
+            5 => return None,
+            7 => Self::ConnexionError,
+            8 => Self::TangencyError,
+            9 => Self::FirstEdgeDegenerated,
+            10 => Self::LastEdgeDegenerated,
+            11 => Self::BothEdgesDegenerated,
+            12 => Self::NotAuthorized,
+            // `Ready` is never reported after an operation was added
+            _ => Self::ComputationError,
+        })
+    }
+
+    fn result<T: TryFrom<Shape>>(rc: u32, shape: Shape) -> Result<T, Self> {
+        match Self::from_status(rc) {
+            None => shape.try_into().map_err(|_| Self::ComputationError),
+            Some(error) => Err(error),
+        }
+    }
+}
+
+impl Face {
+    /// Round the corner of planar face at the given vertex
+    pub fn fillet_2d(
+        &self,
+        vertex: impl AsRef<Vertex>,
+        radius: f64,
+    ) -> Result<Face, Fillet2dError> {
+        let vertex = vertex.as_ref();
+        let mut shape = Shape::default();
+        let r = &mut shape;
+        let rc = unsafe {
+            cpp!([self as "const unique_ptr<TopoDS_Face>*", vertex as "const unique_ptr<TopoDS_Vertex>*", radius as "Standard_Real", r as "unique_ptr<TopoDS_Shape>*"] -> u32 as "ChFi2d_ConstructionError" {
+                try {
+                    BRepFilletAPI_MakeFillet2d b(**self);
+                    if (b.Status() != ChFi2d_Ready) {
+                        return b.Status();
+                    }
+                    b.AddFillet(**vertex, radius);
+                    if (b.Status() != ChFi2d_IsDone) {
+                        return b.Status();
+                    }
+                    b.Build();
+                    if (!b.IsDone()) {
+                        return ChFi2d_ComputationError;
+                    }
+                    *r = unique_ptr<TopoDS_Shape>(new TopoDS_Shape(b.Shape()));
+                    return ChFi2d_IsDone;
+                } catch (const Standard_Failure&) {
+                    return ChFi2d_ComputationError;
+                }
+            })
+        };
+        Fillet2dError::result(rc, shape)
+    }
+
+    /// Bevel the corner of planar face between two adjacent edges
+    ///
+    /// The distances are measured along the first and the second edge respectively.
+    pub fn chamfer_2d(
+        &self,
+        edge1: impl AsRef<Edge>,
+        edge2: impl AsRef<Edge>,
+        distance1: f64,
+        distance2: f64,
+    ) -> Result<Face, Fillet2dError> {
+        let edge1 = edge1.as_ref();
+        let edge2 = edge2.as_ref();
+        let mut shape = Shape::default();
+        let r = &mut shape;
+        let rc = unsafe {
+            cpp!([self as "const unique_ptr<TopoDS_Face>*", edge1 as "const unique_ptr<TopoDS_Edge>*", edge2 as "const unique_ptr<TopoDS_Edge>*", distance1 as "Standard_Real", distance2 as "Standard_Real", r as "unique_ptr<TopoDS_Shape>*"] -> u32 as "ChFi2d_ConstructionError" {
+                try {
+                    BRepFilletAPI_MakeFillet2d b(**self);
+                    if (b.Status() != ChFi2d_Ready) {
+                        return b.Status();
+                    }
+                    b.AddChamfer(**edge1, **edge2, distance1, distance2);
+                    if (b.Status() != ChFi2d_IsDone) {
+                        return b.Status();
+                    }
+                    b.Build();
+                    if (!b.IsDone()) {
+                        return ChFi2d_ComputationError;
+                    }
+                    *r = unique_ptr<TopoDS_Shape>(new TopoDS_Shape(b.Shape()));
+                    return ChFi2d_IsDone;
+                } catch (const Standard_Failure&) {
+                    return ChFi2d_ComputationError;
+                }
+            })
+        };
+        Fillet2dError::result(rc, shape)
+    }
+}
+
+impl Wire {
+    /// Round every corner of planar closed wire
+    pub fn fillet_corners(&self, radius: f64) -> Result<Wire, Fillet2dError> {
+        let closed = unsafe {
+            cpp!([self as "const unique_ptr<TopoDS_Wire>*"] -> bool as "Standard_Boolean" {
+                return BRep_Tool::IsClosed(**self);
+            })
+        };
+        if !closed {
+            return Err(Fillet2dError::OpenWire);
+        }
+
+        let mut shape = Shape::default();
+        let r = &mut shape;
+        let rc = unsafe {
+            cpp!([self as "const unique_ptr<TopoDS_Wire>*", radius as "Standard_Real", r as "unique_ptr<TopoDS_Shape>*"] -> u32 as "ChFi2d_ConstructionError" {
+                try {
+                    BRepBuilderAPI_MakeFace f(**self, Standard_True);
+                    if (!f.IsDone()) {
+                        return ChFi2d_NotPlanar;
+                    }
+                    TopoDS_Face face = f.Face();
+                    BRepFilletAPI_MakeFillet2d b(face);
+                    if (b.Status() != ChFi2d_Ready) {
+                        return b.Status();
+                    }
+                    TopTools_IndexedDataMapOfShapeListOfShape vertex_edges;
+                    TopExp::MapShapesAndUniqueAncestors(face, TopAbs_VERTEX, TopAbs_EDGE, vertex_edges);
+                    for (Standard_Integer i = 1; i <= vertex_edges.Extent(); i++) {
+                        if (vertex_edges(i).Extent() < 2) {
+                            continue;
+                        }
+                        b.AddFillet(TopoDS::Vertex(vertex_edges.FindKey(i)), radius);
+                        if (b.Status() != ChFi2d_IsDone) {
+                            return b.Status();
+                        }
+                    }
+                    b.Build();
+                    if (!b.IsDone()) {
+                        return ChFi2d_ComputationError;
+                    }
+                    *r = unique_ptr<TopoDS_Shape>(new TopoDS_Shape(BRepTools::OuterWire(TopoDS::Face(b.Shape()))));
+                    return ChFi2d_IsDone;
+                } catch (const Standard_Failure&) {
+                    return ChFi2d_ComputationError;
+                }
+            })
+        };
+        Fillet2dError::result(rc, shape)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn fillet_corners() {
+        let p1 = [0.0, 0.0, 0.0];
+        let p2 = [2.0, 0.0, 0.0];
+        let p3 = [2.0, 2.0, 0.0];
+        let p4 = [0.0, 2.0, 0.0];
+
+        let e1 = Edge::try_from((&p1, &p2)).unwrap();
+        let e2 = Edge::try_from((&p2, &p3)).unwrap();
+        let e3 = Edge::try_from((&p3, &p4)).unwrap();
+        let e4 = Edge::try_from((&p4, &p1)).unwrap();
+
+        let w1 = Wire::from_edges([&e1, &e2, &e3, &e4]).unwrap();
+        let w2 = w1.fillet_corners(0.5).unwrap();
+        let w3 = w1.fillet_corners(5.0);
+
+        assert_eq!(w2.traverse::<Edge>().count(), 8);
+        assert!(w3.is_err());
+
+        let w4 = Wire::from_edges([&e1, &e2, &e3]).unwrap();
+
+        assert_eq!(w4.fillet_corners(0.5).err(), Some(Fillet2dError::OpenWire));
+
+        let f1 = Face::from_wires([&w1]).unwrap();
+        let v1 = f1.traverse::<Vertex>().next().unwrap();
+        let f2 = f1.fillet_2d(&*v1, 0.5).unwrap();
+
+        assert_eq!(f2.traverse::<Edge>().count(), 5);
+
+        let f3 = f1.chamfer_2d(&e1, &e2, 0.5, 1.0).unwrap();
+
+        let has_vertex = |p: [f64; 3]| {
+            f3.traverse::<Vertex>().any(|v| {
+                let q = v.point::<[f64; 3]>();
+                (0..3).all(|i| (q[i] - p[i]).abs() < 1e-9)
+            })
+        };
+
+        assert_eq!(f3.traverse::<Edge>().count(), 5);
+        assert!(has_vertex([1.5, 0.0, 0.0]));
+        assert!(has_vertex([2.0, 1.0, 0.0]));
+        assert!(!has_vertex(p2));
+
+        let r1 = f1.chamfer_2d(&e1, &e3, 0.5, 0.5);
+
+        assert!(r1.is_err());
+    }
+}
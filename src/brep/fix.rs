@@ -0,0 +1,280 @@
+use super::{ListOfShape, Shape, TypedShape};
+use crate::message::Messages;
+use cpp::cpp;
+
+cpp! {{
+    #include <memory>
+
+    #include <string>
+    #include <vector>
+
+    #include <Standard_Failure.hxx>
+    #include <TCollection_AsciiString.hxx>
+    #include <Message_Msg.hxx>
+    #include <Message_ListOfMsg.hxx>
+
+    #include <TopoDS_Shape.hxx>
+    #include <TopExp.hxx>
+    #include <TopTools_ListOfShape.hxx>
+    #include <TopTools_IndexedMapOfShape.hxx>
+
+    #include <ShapeBuild_ReShape.hxx>
+    #include <ShapeExtend_MsgRegistrator.hxx>
+    #include <ShapeExtend_DataMapOfShapeListOfMsg.hxx>
+    #include <ShapeFix_Shape.hxx>
+    #include <ShapeFix_Face.hxx>
+    #include <ShapeFix_Wire.hxx>
+
+    using namespace std;
+
+    // Remember the shapes which got failure messages
+    class FixRegistrator : public ShapeExtend_MsgRegistrator {
+    public:
+        using ShapeExtend_MsgRegistrator::Send;
+
+        void Send(const TopoDS_Shape& shape, const Message_Msg& message, const Message_Gravity gravity) override {
+            ShapeExtend_MsgRegistrator::Send(shape, message, gravity);
+            if (!shape.IsNull() && gravity >= Message_Alarm) {
+                failed.Add(shape);
+            }
+        }
+
+        TopTools_IndexedMapOfShape failed;
+    };
+}}
+
+/// Shape healing options
+///
+/// The modes which are set to `None` are chosen by algorithm.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct FixOptions {
+    /// The basic precision of fixing
+    pub precision: f64,
+    /// The minimal tolerance which may be set on sub-shapes
+    pub min_tolerance: f64,
+    /// The maximal tolerance which may be set on sub-shapes
+    pub max_tolerance: f64,
+    /// Fix solids
+    pub fix_solids: Option<bool>,
+    /// Fix shells which do not belong to solids
+    pub fix_free_shells: Option<bool>,
+    /// Fix faces which do not belong to shells
+    pub fix_free_faces: Option<bool>,
+    /// Fix wires which do not belong to faces
+    pub fix_free_wires: Option<bool>,
+    /// Fix the same parameter flag of edges
+    pub fix_same_parameter: Option<bool>,
+    /// Fix the orientation of wires on faces
+    pub fix_orientation: Option<bool>,
+    /// Add missing seam edges on closed surfaces
+    pub fix_missing_seam: Option<bool>,
+    /// Remove small edges from wires
+    pub fix_small_edges: Option<bool>,
+    /// Fix degenerated edges of wires
+    pub fix_degenerated: Option<bool>,
+    /// Fix self-intersecting wires
+    pub fix_self_intersection: Option<bool>,
+}
+
+impl Default for FixOptions {
+    fn default() -> Self {
+        Self {
+            precision: 1e-7,
+            min_tolerance: 1e-7,
+            max_tolerance: 1.0,
+            fix_solids: None,
+            fix_free_shells: None,
+            fix_free_faces: None,
+            fix_free_wires: None,
+            fix_same_parameter: None,
+            fix_orientation: None,
+            fix_missing_seam: None,
+            fix_small_edges: None,
+            fix_degenerated: None,
+            fix_self_intersection: None,
+        }
+    }
+}
+
+/// Shape healing error
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct FixError;
+
+/// The report of shape healing
+#[derive(Clone, Debug, Default)]
+pub struct FixReport {
+    /// Some fixes were applied to shape
+    pub modified: bool,
+    /// Some fixes failed
+    pub failed: bool,
+    /// The sub-shapes of original shape which were replaced or removed
+    pub modified_shapes: Vec<TypedShape>,
+    /// The sub-shapes which could not be fixed
+    pub failed_shapes: Vec<TypedShape>,
+    /// The names of messages recorded by fixes
+    pub messages: Vec<String>,
+}
+
+/// The result of shape healing
+pub struct FixOutput {
+    /// The healed shape
+    pub shape: Shape,
+    /// The report of what was changed
+    pub report: FixReport,
+}
+
+fn fix_mode(mode: Option<bool>) -> i32 {
+    match mode {
+        None => -1,
+        Some(false) => 0,
+        Some(true) => 1,
+    }
+}
+
+impl Shape {
+    /// Heal shape to fix topological and geometrical problems
+    pub fn fix(&self, options: &FixOptions) -> Result<FixOutput, FixError> {
+        let precision = options.precision;
+        let min_tolerance = options.min_tolerance;
+        let max_tolerance = options.max_tolerance;
+        let modes = [
+            options.fix_solids,
+            options.fix_free_shells,
+            options.fix_free_faces,
+            options.fix_free_wires,
+            options.fix_same_parameter,
+            options.fix_orientation,
+            options.fix_missing_seam,
+            options.fix_small_edges,
+            options.fix_degenerated,
+            options.fix_self_intersection,
+        ]
+        .map(fix_mode);
+        let modes = &modes;
+
+        let mut shape = Shape::default();
+        let r = &mut shape;
+        let mut messages = Messages::new();
+        let w = &mut messages;
+        let mut flags = [false; 2];
+        let f = &mut flags;
+        let mut modified_shapes = ListOfShape::new();
+        let m = &mut modified_shapes;
+        let mut failed_shapes = ListOfShape::new();
+        let e = &mut failed_shapes;
+
+        let ok = unsafe {
+            cpp!([self as "const unique_ptr<TopoDS_Shape>*", precision as "Standard_Real", min_tolerance as "Standard_Real", max_tolerance as "Standard_Real", modes as "const Standard_Integer*", r as "unique_ptr<TopoDS_Shape>*", w as "unique_ptr<vector<string>>*", f as "bool*", m as "unique_ptr<TopTools_ListOfShape>*", e as "unique_ptr<TopTools_ListOfShape>*"] -> bool as "Standard_Boolean" {
+                try {
+                    ShapeFix_Shape fix(**self);
+                    Handle(FixRegistrator) registrator = new FixRegistrator();
+                    fix.SetMsgRegistrator(registrator);
+                    fix.SetPrecision(precision);
+                    fix.SetMinTolerance(min_tolerance);
+                    fix.SetMaxTolerance(max_tolerance);
+                    fix.FixSolidMode() = modes[0];
+                    fix.FixFreeShellMode() = modes[1];
+                    fix.FixFreeFaceMode() = modes[2];
+                    fix.FixFreeWireMode() = modes[3];
+                    fix.FixSameParameterMode() = modes[4];
+                    fix.FixFaceTool()->FixOrientationMode() = modes[5];
+                    fix.FixFaceTool()->FixMissingSeamMode() = modes[6];
+                    fix.FixWireTool()->FixSmallMode() = modes[7];
+                    fix.FixWireTool()->FixDegeneratedMode() = modes[8];
+                    fix.FixWireTool()->FixSelfIntersectionMode() = modes[9];
+                    fix.Perform();
+                    f[0] = fix.Status(ShapeExtend_DONE);
+                    f[1] = fix.Status(ShapeExtend_FAIL);
+                    TopTools_IndexedMapOfShape shapes;
+                    TopExp::MapShapes(**self, shapes);
+                    for (Standard_Integer i = 1; i <= shapes.Extent(); i++) {
+                        if (fix.Context()->IsRecorded(shapes(i))) {
+                            (*m)->Append(shapes(i));
+                        }
+                    }
+                    for (Standard_Integer i = 1; i <= registrator->failed.Extent(); i++) {
+                        (*e)->Append(registrator->failed(i));
+                    }
+                    for (ShapeExtend_DataMapOfShapeListOfMsg::Iterator it(registrator->MapShape()); it.More(); it.Next()) {
+                        for (Message_ListOfMsg::Iterator msg(it.Value()); msg.More(); msg.Next()) {
+                            (*w)->push_back(TCollection_AsciiString(msg.Value().Original()).ToCString());
+                        }
+                    }
+                    *r = unique_ptr<TopoDS_Shape>(new TopoDS_Shape(fix.Shape()));
+                    return Standard_True;
+                } catch (const Standard_Failure&) {
+                    return Standard_False;
+                }
+            })
+        };
+
+        if ok && !shape.is_null() {
+            Ok(FixOutput {
+                shape,
+                report: FixReport {
+                    modified: flags[0],
+                    failed: flags[1],
+                    modified_shapes: modified_shapes.into_vec(),
+                    failed_shapes: failed_shapes.into_vec(),
+                    messages: messages.to_vec(),
+                },
+            })
+        } else {
+            Err(FixError)
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::{Face, Solid};
+    use super::*;
+    use crate::fixtures::*;
+
+    cpp! {{
+        #include <TopoDS.hxx>
+        #include <TopoDS_Face.hxx>
+
+        #include <BRep_Builder.hxx>
+        #include <BRepTools.hxx>
+    }}
+
+    /// Copy face with reversed outer wire
+    fn reverse_wire(face: &Face) -> Face {
+        let mut shape = Shape::default();
+        let r = &mut shape;
+        unsafe {
+            cpp!([face as "const unique_ptr<TopoDS_Face>*", r as "unique_ptr<TopoDS_Shape>*"] {
+                BRep_Builder b;
+                TopoDS_Face f = TopoDS::Face((*face)->EmptyCopied());
+                b.Add(f, BRepTools::OuterWire(**face).Reversed());
+                *r = unique_ptr<TopoDS_Shape>(new TopoDS_Shape(f));
+            })
+        };
+        shape.try_into().unwrap()
+    }
+
+    #[test]
+    fn fix() {
+        let s1 = cube([0.0, 0.0, 0.0], 1.0);
+
+        let r1 = s1.fix(&FixOptions::default()).unwrap();
+
+        assert!(r1.shape.is_valid());
+        assert!(!r1.report.failed);
+        assert!(r1.report.failed_shapes.is_empty());
+        assert_eq!(r1.shape.traverse::<Solid>().count(), 1);
+
+        let f1 = reverse_wire(&s1.traverse::<Face>().next().unwrap());
+
+        let r2 = f1.fix(&FixOptions::default()).unwrap();
+
+        assert!(!f1.is_valid());
+        assert!(r2.shape.is_valid());
+        assert!(r2.report.modified);
+        assert!(!r2.report.modified_shapes.is_empty());
+        assert!(r2.report.failed_shapes.is_empty());
+        assert!(!r2.report.messages.is_empty());
+        assert_eq!(r2.shape.traverse::<Face>().count(), 1);
+    }
+}
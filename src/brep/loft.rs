@@ -0,0 +1,145 @@
+use super::{ListOfShape, Shape, TypedShape, Vertex, Wire};
+use cpp::cpp;
+
+cpp! {{
+    #include <memory>
+
+    #include <Standard_Failure.hxx>
+
+    #include <TopoDS.hxx>
+    #include <TopoDS_Shape.hxx>
+    #include <TopoDS_Vertex.hxx>
+    #include <TopTools_ListOfShape.hxx>
+
+    #include <BRepOffsetAPI_ThruSections.hxx>
+
+    using namespace std;
+}}
+
+/// Loft construction error
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct LoftError;
+
+/// Loft options
+#[derive(Clone, Debug)]
+pub struct LoftOptions {
+    /// Close the ends to make solid instead of shell
+    pub solid: bool,
+    /// Connect sections by ruled surfaces instead of smooth one
+    pub ruled: bool,
+    /// Check and fix the compatibility of sections before construction
+    pub check_compatibility: bool,
+    /// The point where loft starts before the first section
+    pub start: Option<Vertex>,
+    /// The point where loft ends after the last section
+    pub end: Option<Vertex>,
+}
+
+impl Default for LoftOptions {
+    fn default() -> Self {
+        Self {
+            solid: false,
+            ruled: false,
+            check_compatibility: true,
+            start: None,
+            end: None,
+        }
+    }
+}
+
+impl Shape {
+    /// Make shell or solid which passes through the given sections
+    pub fn loft<T: AsRef<Wire>>(
+        sections: impl IntoIterator<Item = T>,
+        options: &LoftOptions,
+    ) -> Result<TypedShape, LoftError> {
+        let mut list = ListOfShape::new();
+        for section in sections {
+            list.push(section.as_ref());
+        }
+
+        let count = list.len() + options.start.is_some() as usize + options.end.is_some() as usize;
+        if list.is_empty() || count < 2 {
+            return Err(LoftError);
+        }
+
+        let sections = &list;
+        let solid = options.solid;
+        let ruled = options.ruled;
+        let check_compatibility = options.check_compatibility;
+        let null = Shape::default();
+        let start = options.start.as_deref().unwrap_or(&null);
+        let end = options.end.as_deref().unwrap_or(&null);
+
+        let mut shape = Shape::default();
+        let r = &mut shape;
+
+        let ok = unsafe {
+            cpp!([sections as "const unique_ptr<TopTools_ListOfShape>*", solid as "Standard_Boolean", ruled as "Standard_Boolean", check_compatibility as "Standard_Boolean", start as "const unique_ptr<TopoDS_Shape>*", end as "const unique_ptr<TopoDS_Shape>*", r as "unique_ptr<TopoDS_Shape>*"] -> bool as "Standard_Boolean" {
+                try {
+                    BRepOffsetAPI_ThruSections b(solid, ruled);
+                    b.CheckCompatibility(check_compatibility);
+                    if (!(*start)->IsNull()) {
+                        b.AddVertex(TopoDS::Vertex(**start));
+                    }
+                    for (TopTools_ListOfShape::Iterator it(**sections); it.More(); it.Next()) {
+                        b.AddWire(TopoDS::Wire(it.Value()));
+                    }
+                    if (!(*end)->IsNull()) {
+                        b.AddVertex(TopoDS::Vertex(**end));
+                    }
+                    b.Build();
+                    if (!b.IsDone()) {
+                        return Standard_False;
+                    }
+                    *r = unique_ptr<TopoDS_Shape>(new TopoDS_Shape(b.Shape()));
+                    return Standard_True;
+                } catch (const Standard_Failure&) {
+                    return Standard_False;
+                }
+            })
+        };
+
+        if ok {
+            shape.try_into().map_err(|_| LoftError)
+        } else {
+            Err(LoftError)
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::Solid;
+    use super::*;
+    use crate::fixtures::*;
+
+    #[test]
+    fn loft() {
+        let w1 = square(0.0, 1.0);
+        let w2 = square(1.0, 0.5);
+        let w3 = square(2.0, 1.0);
+
+        let o1 = LoftOptions {
+            solid: true,
+            ruled: true,
+            ..Default::default()
+        };
+        let r1 = Shape::loft([&w1, &w2, &w3], &o1).unwrap();
+        let r2 = Shape::loft([&w1, &w2], &LoftOptions::default()).unwrap();
+
+        let o3 = LoftOptions {
+            solid: true,
+            end: Some(Vertex::from(&[0.0, 0.0, 3.0])),
+            ..Default::default()
+        };
+        let r3 = Shape::loft([&w1], &o3).unwrap();
+        let r4 = Shape::loft([&w1], &LoftOptions::default());
+
+        assert!(matches!(r1, TypedShape::Solid(_)));
+        assert!(matches!(r2, TypedShape::Shell(_)));
+        assert!((Shape::from(r3).volume() - 4.0).abs() < 1e-6);
+        assert_eq!(r4.err(), Some(LoftError));
+        assert_eq!(Shape::from(r1).traverse::<Solid>().count(), 1);
+    }
+}
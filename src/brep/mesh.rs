@@ -0,0 +1,203 @@
+use super::{Angle, AngleUnits, Face, Point, Shape, Vector};
+use core::ops::Range;
+use cpp::cpp;
+
+cpp! {{
+    #include <memory>
+    #include <cstdint>
+
+    #include <Standard_Failure.hxx>
+
+    #include <gp_Pnt.hxx>
+    #include <gp_Pnt2d.hxx>
+    #include <gp_Vec.hxx>
+    #include <gp_Dir.hxx>
+
+    #include <TopLoc_Location.hxx>
+    #include <TopoDS_Shape.hxx>
+    #include <TopoDS_Face.hxx>
+
+    #include <BRep_Tool.hxx>
+    #include <BRepLib_ToolTriangulatedShape.hxx>
+    #include <BRepMesh_IncrementalMesh.hxx>
+    #include <Poly_Triangulation.hxx>
+
+    using namespace std;
+}}
+
+/// Triangle mesh of shape
+#[derive(Clone, Debug, Default)]
+pub struct Mesh {
+    /// The positions of nodes
+    pub positions: Vec<Point>,
+    /// The normals of nodes
+    pub normals: Vec<Vector>,
+    /// The surface parameters of nodes
+    pub uvs: Vec<[f64; 2]>,
+    /// The node indices of triangles (three per triangle)
+    pub indices: Vec<u32>,
+    /// The ranges of indices which belong to each face
+    pub face_ranges: Vec<Range<usize>>,
+}
+
+/// Triangulation error
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct MeshError;
+
+impl Shape {
+    /// Triangulate shape
+    ///
+    /// The linear deflection is the maximum distance between triangles and surface.
+    /// The angular deflection is the maximum angle between normals of adjacent triangles.
+    pub fn triangulate(
+        &self,
+        linear_deflection: f64,
+        angular_deflection: impl AsRef<Angle>,
+    ) -> Result<Mesh, MeshError> {
+        let angular_deflection = *angular_deflection.as_ref().to(AngleUnits::Rad).raw();
+
+        let ok = unsafe {
+            cpp!([self as "const unique_ptr<TopoDS_Shape>*", linear_deflection as "Standard_Real", angular_deflection as "Standard_Real"] -> bool as "Standard_Boolean" {
+                try {
+                    BRepMesh_IncrementalMesh mesh(**self, linear_deflection, Standard_False, angular_deflection, Standard_True);
+                    return mesh.IsDone();
+                } catch (const Standard_Failure&) {
+                    return Standard_False;
+                }
+            })
+        };
+
+        if !ok {
+            return Err(MeshError);
+        }
+
+        let mut mesh = Mesh::default();
+
+        for face in self.traverse::<Face>() {
+            mesh.push_face(&face)?;
+        }
+
+        Ok(mesh)
+    }
+}
+
+impl Mesh {
+    fn push_face(&mut self, face: &Face) -> Result<(), MeshError> {
+        let mut nodes = 0i32;
+        let mut triangles = 0i32;
+        let n = &mut nodes;
+        let t = &mut triangles;
+
+        let ok = unsafe {
+            cpp!([face as "const unique_ptr<TopoDS_Face>*", n as "Standard_Integer*", t as "Standard_Integer*"] -> bool as "Standard_Boolean" {
+                try {
+                    TopLoc_Location location;
+                    const Handle(Poly_Triangulation)& triangulation = BRep_Tool::Triangulation(**face, location);
+                    if (!triangulation.IsNull()) {
+                        *n = triangulation->NbNodes();
+                        *t = triangulation->NbTriangles();
+                    }
+                    return Standard_True;
+                } catch (const Standard_Failure&) {
+                    return Standard_False;
+                }
+            })
+        };
+
+        if !ok {
+            return Err(MeshError);
+        }
+
+        let base = self.positions.len();
+        let start = self.indices.len();
+        let nodes = nodes as usize;
+        let end = start + triangles as usize * 3;
+
+        self.positions.resize(base + nodes, Point::default());
+        self.normals.resize(base + nodes, Vector::default());
+        self.uvs.resize(base + nodes, [0.0; 2]);
+        self.indices.resize(end, 0);
+        self.face_ranges.push(start..end);
+
+        if nodes == 0 {
+            return Ok(());
+        }
+
+        let positions = self.positions[base..].as_mut_ptr();
+        let normals = self.normals[base..].as_mut_ptr();
+        let uvs = self.uvs[base..].as_mut_ptr();
+        let indices = self.indices[start..].as_mut_ptr();
+        let base = base as u32;
+
+        let ok = unsafe {
+            cpp!([face as "const unique_ptr<TopoDS_Face>*", positions as "gp_Pnt*", normals as "gp_Vec*", uvs as "gp_Pnt2d*", indices as "uint32_t*", base as "uint32_t"] -> bool as "Standard_Boolean" {
+                try {
+                    TopLoc_Location location;
+                    const Handle(Poly_Triangulation)& triangulation = BRep_Tool::Triangulation(**face, location);
+                    const gp_Trsf& transformation = location.Transformation();
+                    bool reversed = (*face)->Orientation() == TopAbs_REVERSED;
+
+                    if (!triangulation->HasNormals()) {
+                        BRepLib_ToolTriangulatedShape::ComputeNormals(**face, triangulation);
+                    }
+
+                    for (Standard_Integer i = 1; i <= triangulation->NbNodes(); i++) {
+                        positions[i - 1] = triangulation->Node(i).Transformed(transformation);
+                        gp_Dir normal = triangulation->Normal(i).Transformed(transformation);
+                        if (reversed) {
+                            normal.Reverse();
+                        }
+                        normals[i - 1] = gp_Vec(normal);
+                        if (triangulation->HasUVNodes()) {
+                            uvs[i - 1] = triangulation->UVNode(i);
+                        }
+                    }
+
+                    for (Standard_Integer i = 1; i <= triangulation->NbTriangles(); i++) {
+                        Standard_Integer n1, n2, n3;
+                        triangulation->Triangle(i).Get(n1, n2, n3);
+                        if (reversed) {
+                            swap(n2, n3);
+                        }
+                        indices[(i - 1) * 3] = base + n1 - 1;
+                        indices[(i - 1) * 3 + 1] = base + n2 - 1;
+                        indices[(i - 1) * 3 + 2] = base + n3 - 1;
+                    }
+                    return Standard_True;
+                } catch (const Standard_Failure&) {
+                    return Standard_False;
+                }
+            })
+        };
+
+        if ok {
+            Ok(())
+        } else {
+            Err(MeshError)
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::Solid;
+    use super::*;
+    use crate::fixtures::*;
+
+    #[test]
+    fn triangulate() {
+        let s1 = Solid::box_(axis([0.0, 0.0, 0.0]), 1.0, 2.0, 3.0).unwrap();
+        let m1 = s1
+            .triangulate(0.1, Angle::new(0.5, AngleUnits::Rad))
+            .unwrap();
+
+        assert_eq!(m1.face_ranges.len(), 6);
+        assert_eq!(m1.positions.len(), 24);
+        assert_eq!(m1.normals.len(), 24);
+        assert_eq!(m1.indices.len(), 36);
+        assert!(m1
+            .indices
+            .iter()
+            .all(|index| (*index as usize) < m1.positions.len()));
+    }
+}
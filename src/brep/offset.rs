@@ -0,0 +1,297 @@
+use super::{Face, ListOfShape, Shape, Solid, Wire};
+use cpp::cpp;
+
+cpp! {{
+    #include <memory>
+
+    #include <Standard_Failure.hxx>
+
+    #include <TopoDS.hxx>
+    #include <TopoDS_Shape.hxx>
+    #include <TopoDS_Wire.hxx>
+    #include <TopoDS_Face.hxx>
+    #include <TopoDS_Solid.hxx>
+    #include <TopTools_ListOfShape.hxx>
+
+    #include <BRep_Tool.hxx>
+    #include <BRepOffsetAPI_MakeOffset.hxx>
+    #include <BRepOffsetAPI_MakeOffsetShape.hxx>
+    #include <BRepOffsetAPI_MakeThickSolid.hxx>
+
+    using namespace std;
+}}
+
+/// The way to fill the gaps between offset faces or edges
+///
+/// The values match `GeomAbs_JoinType` except the tangent join which offset algorithms do not support.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[repr(u32)]
+pub enum JoinType {
+    /// The gaps are filled by pipes or arcs.
+    Arc = 0,
+    /// The offset faces or edges are extended until they intersect.
+    Intersection = 2,
+}
+
+enum_impls! {
+    /// Offset construction error
+    OffsetError {
+        /// The algorithm failed for unknown reason.
+        UnknownError = 1,
+        /// The normals on some of surfaces cannot be computed.
+        BadNormalsOnGeometry,
+        /// The shape contains C0 continuous geometry.
+        C0Geometry,
+        /// The offset value is zero.
+        NullOffset,
+        /// The shell is not connected.
+        NotConnectedShell,
+        /// The edges cannot be trimmed.
+        CannotTrimEdges,
+        /// The vertices cannot be fused.
+        CannotFuseVertices,
+        /// The edges cannot be extended.
+        CannotExtentEdge,
+        /// The operation was interrupted.
+        UserBreak,
+        /// The shape has mixed connectivity of faces.
+        MixedConnectivity,
+    }
+}
+
+/// Offset options
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct OffsetOptions {
+    /// The tolerance of coincidence of generated shapes
+    pub tolerance: f64,
+    /// The way to fill the gaps between offset faces
+    pub join: JoinType,
+    /// Compute intersections between all parallel faces instead of adjacent only
+    pub intersection: bool,
+    /// Compute self-intersections of the result
+    pub self_intersection: bool,
+    /// Remove the internal edges from result
+    pub remove_internal_edges: bool,
+}
+
+impl Default for OffsetOptions {
+    fn default() -> Self {
+        Self {
+            tolerance: 1e-6,
+            join: JoinType::Arc,
+            intersection: false,
+            self_intersection: false,
+            remove_internal_edges: false,
+        }
+    }
+}
+
+impl Shape {
+    /// Make shape which surface is offset from surface of this one by the given distance
+    ///
+    /// The positive distance offsets outside and the negative distance offsets inside.
+    pub fn offset(&self, distance: f64, options: &OffsetOptions) -> Result<Shape, OffsetError> {
+        self.make_offset(None, distance, options)
+    }
+
+    fn make_offset(
+        &self,
+        faces: Option<&ListOfShape>,
+        distance: f64,
+        options: &OffsetOptions,
+    ) -> Result<Shape, OffsetError> {
+        let thick = faces.is_some();
+        let empty = ListOfShape::new();
+        let faces = faces.unwrap_or(&empty);
+        let tolerance = options.tolerance;
+        let join = options.join;
+        let intersection = options.intersection;
+        let self_intersection = options.self_intersection;
+        let remove_internal_edges = options.remove_internal_edges;
+
+        let mut shape = Shape::default();
+        let r = &mut shape;
+
+        let rc = unsafe {
+            cpp!([self as "const unique_ptr<TopoDS_Shape>*", thick as "Standard_Boolean", faces as "const unique_ptr<TopTools_ListOfShape>*", distance as "Standard_Real", tolerance as "Standard_Real", join as "GeomAbs_JoinType", intersection as "Standard_Boolean", self_intersection as "Standard_Boolean", remove_internal_edges as "Standard_Boolean", r as "unique_ptr<TopoDS_Shape>*"] -> u32 as "BRepOffset_Error" {
+                try {
+                    if (thick) {
+                        BRepOffsetAPI_MakeThickSolid b;
+                        b.MakeThickSolidByJoin(**self, **faces, distance, tolerance, BRepOffset_Skin, intersection, self_intersection, join, remove_internal_edges);
+                        if (!b.IsDone()) {
+                            return b.GetError() != BRepOffset_NoError ? b.GetError() : BRepOffset_UnknownError;
+                        }
+                        *r = unique_ptr<TopoDS_Shape>(new TopoDS_Shape(b.Shape()));
+                    } else {
+                        BRepOffsetAPI_MakeOffsetShape b;
+                        b.PerformByJoin(**self, distance, tolerance, BRepOffset_Skin, intersection, self_intersection, join, remove_internal_edges);
+                        if (!b.IsDone()) {
+                            return b.GetError() != BRepOffset_NoError ? b.GetError() : BRepOffset_UnknownError;
+                        }
+                        *r = unique_ptr<TopoDS_Shape>(new TopoDS_Shape(b.Shape()));
+                    }
+                    return BRepOffset_NoError;
+                } catch (const Standard_Failure&) {
+                    return BRepOffset_UnknownError;
+                }
+            })
+        };
+
+        if let Ok(err) = rc.try_into() {
+            Err(err)
+        } else {
+            Ok(shape)
+        }
+    }
+}
+
+impl Solid {
+    /// Make hollow solid with walls of given thickness
+    ///
+    /// The removed faces become the openings of the hollow solid.
+    /// The positive thickness adds the walls outside and the negative thickness adds them inside.
+    pub fn hollow<T: AsRef<Face>>(
+        &self,
+        faces: impl IntoIterator<Item = T>,
+        thickness: f64,
+        options: &OffsetOptions,
+    ) -> Result<Solid, OffsetError> {
+        let mut list = ListOfShape::new();
+        for face in faces {
+            list.push(face.as_ref());
+        }
+
+        self.make_offset(Some(&list), thickness, options)?
+            .try_into()
+            .map_err(|_| OffsetError::UnknownError)
+    }
+}
+
+impl Shape {
+    fn make_offset_2d(&self, distance: f64, join: JoinType) -> Result<Vec<Wire>, OffsetError> {
+        let mut shape = Shape::default();
+        let r = &mut shape;
+
+        let ok = unsafe {
+            cpp!([self as "const unique_ptr<TopoDS_Shape>*", distance as "Standard_Real", join as "GeomAbs_JoinType", r as "unique_ptr<TopoDS_Shape>*"] -> bool as "Standard_Boolean" {
+                try {
+                    BRepOffsetAPI_MakeOffset b;
+                    if ((*self)->ShapeType() == TopAbs_FACE) {
+                        b.Init(TopoDS::Face(**self), join, Standard_False);
+                    } else {
+                        const TopoDS_Wire& wire = TopoDS::Wire(**self);
+                        b.Init(join, !BRep_Tool::IsClosed(wire));
+                        b.AddWire(wire);
+                    }
+                    b.Perform(distance);
+                    if (!b.IsDone()) {
+                        return Standard_False;
+                    }
+                    *r = unique_ptr<TopoDS_Shape>(new TopoDS_Shape(b.Shape()));
+                    return Standard_True;
+                } catch (const Standard_Failure&) {
+                    return Standard_False;
+                }
+            })
+        };
+
+        if ok {
+            Ok(shape
+                .traverse::<Wire>()
+                .map(|wire| Wire::clone(&wire))
+                .collect())
+        } else {
+            Err(OffsetError::UnknownError)
+        }
+    }
+}
+
+impl Wire {
+    /// Offset planar wire in its plane
+    ///
+    /// The closed wire is offset outside when distance is positive and inside when it is negative.
+    /// The open wire is offset to one side, so the result is open too.
+    pub fn offset(&self, distance: f64, join: JoinType) -> Result<Vec<Wire>, OffsetError> {
+        self.make_offset_2d(distance, join)
+    }
+}
+
+impl Face {
+    /// Offset the boundary of planar face in its plane
+    ///
+    /// The positive distance enlarges the face and the negative distance shrinks it.
+    pub fn offset_2d(&self, distance: f64, join: JoinType) -> Result<Vec<Wire>, OffsetError> {
+        self.make_offset_2d(distance, join)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::Edge;
+    use super::*;
+    use crate::fixtures::*;
+    use core::f64::consts::PI;
+
+    #[test]
+    fn hollow() {
+        let s1 = cube([0.0, 0.0, 0.0], 10.0);
+        let f1 = s1
+            .traverse::<Face>()
+            .find(|face| face.surface_properties().center_of_mass.z > 9.0)
+            .unwrap();
+
+        let s2 = s1.hollow([&*f1], -1.0, &OffsetOptions::default()).unwrap();
+        let o1 = OffsetOptions {
+            join: JoinType::Intersection,
+            ..Default::default()
+        };
+        let s3 = s1.hollow([&*f1], 1.0, &o1).unwrap();
+
+        assert!((s2.volume() - (1000.0 - 8.0 * 8.0 * 9.0)).abs() < 1e-3);
+        assert!((s3.volume() - (12.0 * 12.0 * 11.0 - 1000.0)).abs() < 1e-3);
+    }
+
+    #[test]
+    fn offset() {
+        let s1 = cube([0.0, 0.0, 0.0], 10.0);
+        let o1 = OffsetOptions::default();
+        let r1 = s1.offset(1.0, &o1).unwrap();
+        let r2 = s1.offset(0.0, &o1);
+        let o2 = OffsetOptions {
+            join: JoinType::Intersection,
+            ..Default::default()
+        };
+        let r3 = s1.offset(1.0, &o2).unwrap();
+
+        assert!((r1.volume() - (1600.0 + 30.0 * PI + 4.0 / 3.0 * PI)).abs() < 1e-3);
+        assert_eq!(r2.err(), Some(OffsetError::NullOffset));
+        assert!((r3.volume() - 12.0 * 12.0 * 12.0).abs() < 1e-3);
+    }
+
+    #[test]
+    fn offset_2d() {
+        let p1 = [0.0, 0.0, 0.0];
+        let p2 = [2.0, 0.0, 0.0];
+        let p3 = [2.0, 2.0, 0.0];
+        let p4 = [0.0, 2.0, 0.0];
+
+        let w1 = polyline(&[p1, p2, p3, p4, p1]);
+        let r1 = w1.offset(0.5, JoinType::Intersection).unwrap();
+        let r2 = w1.offset(0.5, JoinType::Arc).unwrap();
+
+        assert_eq!(r1.len(), 1);
+        assert_eq!(r1[0].traverse::<Edge>().count(), 4);
+        assert_eq!(r2[0].traverse::<Edge>().count(), 8);
+
+        let f1 = Face::from_wires([&w1]).unwrap();
+        let r3 = f1.offset_2d(-0.5, JoinType::Intersection).unwrap();
+        let f2 = Face::from_wires(&r3).unwrap();
+
+        assert!((f2.area() - 1.0).abs() < 1e-6);
+
+        let w2 = polyline(&[p1, p2, p3]);
+        let r4 = w2.offset(0.5, JoinType::Arc).unwrap();
+
+        assert_eq!(r4.len(), 1);
+    }
+}
@@ -0,0 +1,134 @@
+use super::{Point, Shape, Vector};
+use cpp::cpp;
+
+cpp! {{
+    #include <memory>
+    #include <cstdint>
+
+    #include <gp_Pnt.hxx>
+    #include <gp_Vec.hxx>
+    #include <gp_Mat.hxx>
+
+    #include <TopoDS_Shape.hxx>
+
+    #include <GProp_GProps.hxx>
+    #include <GProp_PrincipalProps.hxx>
+    #include <BRepGProp.hxx>
+
+    using namespace std;
+}}
+
+/// Global properties of shape
+///
+/// The mass is the length, area or volume of shape depending on kind of properties.
+/// Inertia is computed for unit density.
+#[derive(Clone, Copy, Debug, PartialEq, Default)]
+pub struct Properties {
+    /// The length, area or volume
+    pub mass: f64,
+    /// The center of mass
+    pub center_of_mass: Point,
+    /// The matrix of inertia relative to the center of mass
+    pub inertia_matrix: [[f64; 3]; 3],
+    /// The principal moments of inertia
+    pub principal_moments: [f64; 3],
+    /// The principal axes of inertia
+    pub principal_axes: [Vector; 3],
+}
+
+#[derive(Clone, Copy)]
+#[repr(u32)]
+enum PropertiesKind {
+    Linear,
+    Surface,
+    Volume,
+}
+
+impl Shape {
+    /// Compute properties of edges
+    pub fn linear_properties(&self) -> Properties {
+        self.make_properties(PropertiesKind::Linear)
+    }
+
+    /// Compute properties of faces
+    pub fn surface_properties(&self) -> Properties {
+        self.make_properties(PropertiesKind::Surface)
+    }
+
+    /// Compute properties of solids
+    pub fn volume_properties(&self) -> Properties {
+        self.make_properties(PropertiesKind::Volume)
+    }
+
+    /// Total length of edges
+    pub fn length(&self) -> f64 {
+        self.linear_properties().mass
+    }
+
+    /// Total area of faces
+    pub fn area(&self) -> f64 {
+        self.surface_properties().mass
+    }
+
+    /// Total volume of solids
+    pub fn volume(&self) -> f64 {
+        self.volume_properties().mass
+    }
+
+    fn make_properties(&self, kind: PropertiesKind) -> Properties {
+        let mut props = Properties::default();
+        let mass = &mut props.mass;
+        let center = &mut props.center_of_mass;
+        let inertia = &mut props.inertia_matrix;
+        let moments = &mut props.principal_moments;
+        let axes = &mut props.principal_axes;
+
+        unsafe {
+            cpp!([self as "const unique_ptr<TopoDS_Shape>*", kind as "uint32_t", mass as "Standard_Real*", center as "gp_Pnt*", inertia as "gp_Mat*", moments as "Standard_Real*", axes as "gp_Vec*"] {
+                GProp_GProps props;
+                switch (kind) {
+                case 0:
+                    BRepGProp::LinearProperties(**self, props);
+                    break;
+                case 1:
+                    BRepGProp::SurfaceProperties(**self, props);
+                    break;
+                default:
+                    BRepGProp::VolumeProperties(**self, props);
+                    break;
+                }
+                *mass = props.Mass();
+                *center = props.CentreOfMass();
+                *inertia = props.MatrixOfInertia();
+                GProp_PrincipalProps principal = props.PrincipalProperties();
+                principal.Moments(moments[0], moments[1], moments[2]);
+                axes[0] = principal.FirstAxisOfInertia();
+                axes[1] = principal.SecondAxisOfInertia();
+                axes[2] = principal.ThirdAxisOfInertia();
+            })
+        };
+
+        props
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::Solid;
+    use super::*;
+    use crate::fixtures::*;
+
+    #[test]
+    fn properties() {
+        let s1 = Solid::box_(axis([0.0, 0.0, 0.0]), 1.0, 2.0, 3.0).unwrap();
+        let p1 = s1.volume_properties();
+
+        assert!((s1.volume() - 6.0).abs() < 1e-9);
+        assert!((s1.area() - 22.0).abs() < 1e-9);
+        assert!((s1.length() - 24.0).abs() < 1e-9);
+        assert!((p1.center_of_mass.x - 0.5).abs() < 1e-9);
+        assert!((p1.center_of_mass.y - 1.0).abs() < 1e-9);
+        assert!((p1.center_of_mass.z - 1.5).abs() < 1e-9);
+        assert!((p1.inertia_matrix[2][2] - 6.0 * (1.0 + 4.0) / 12.0).abs() < 1e-9);
+    }
+}
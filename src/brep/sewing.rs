@@ -0,0 +1,164 @@
+use super::{Edge, Face, ListOfShape, Shape, TypedShape};
+use cpp::cpp;
+
+cpp! {{
+    #include <memory>
+
+    #include <Standard_Failure.hxx>
+
+    #include <TopoDS.hxx>
+    #include <TopoDS_Shape.hxx>
+    #include <TopoDS_Shell.hxx>
+    #include <TopoDS_Solid.hxx>
+    #include <TopTools_ListOfShape.hxx>
+
+    #include <BRep_Tool.hxx>
+    #include <BRepLib.hxx>
+    #include <BRepBuilderAPI_Sewing.hxx>
+    #include <BRepBuilderAPI_MakeSolid.hxx>
+
+    using namespace std;
+}}
+
+/// Sewing error
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SewingError;
+
+/// The result of sewing
+#[derive(Clone, Debug)]
+pub struct SewingOutput {
+    /// The sewed shape
+    ///
+    /// Usually this is a shell or a solid when the shell is closed.
+    pub shape: TypedShape,
+    /// The edges which bound only one face
+    pub free_edges: Vec<Edge>,
+    /// The edges which are shared by more than two faces
+    pub multiple_edges: Vec<Edge>,
+    /// The edges which were degenerated by sewing
+    pub degenerated_edges: Vec<Edge>,
+}
+
+/// Sewing faces into shells and solids
+pub struct Sewing {
+    tolerance: f64,
+    faces: ListOfShape,
+}
+
+impl Sewing {
+    /// Create sewing with given tolerance
+    ///
+    /// The edges which are closer than tolerance will be merged.
+    pub fn new(tolerance: f64) -> Self {
+        Self {
+            tolerance,
+            faces: ListOfShape::new(),
+        }
+    }
+
+    /// Add face
+    pub fn face(mut self, face: impl AsRef<Face>) -> Self {
+        self.faces.push(face.as_ref());
+        self
+    }
+
+    /// Add faces
+    pub fn faces<T: AsRef<Face>>(mut self, faces: impl IntoIterator<Item = T>) -> Self {
+        for face in faces {
+            self.faces.push(face.as_ref());
+        }
+        self
+    }
+
+    /// Perform sewing
+    pub fn build(&self) -> Result<SewingOutput, SewingError> {
+        if self.faces.is_empty() {
+            return Err(SewingError);
+        }
+
+        let tolerance = self.tolerance;
+        let faces = &self.faces;
+
+        let mut shape = Shape::default();
+        let r = &mut shape;
+        let mut free_edges = ListOfShape::new();
+        let mut multiple_edges = ListOfShape::new();
+        let mut degenerated_edges = ListOfShape::new();
+        let fe = &mut free_edges;
+        let me = &mut multiple_edges;
+        let de = &mut degenerated_edges;
+
+        let ok = unsafe {
+            cpp!([tolerance as "Standard_Real", faces as "const unique_ptr<TopTools_ListOfShape>*", r as "unique_ptr<TopoDS_Shape>*", fe as "unique_ptr<TopTools_ListOfShape>*", me as "unique_ptr<TopTools_ListOfShape>*", de as "unique_ptr<TopTools_ListOfShape>*"] -> bool as "Standard_Boolean" {
+                try {
+                    BRepBuilderAPI_Sewing b(tolerance);
+                    for (TopTools_ListOfShape::Iterator it(**faces); it.More(); it.Next()) {
+                        b.Add(it.Value());
+                    }
+                    b.Perform();
+                    TopoDS_Shape shape = b.SewedShape();
+                    if (shape.IsNull()) {
+                        return Standard_False;
+                    }
+                    if (shape.ShapeType() == TopAbs_SHELL && BRep_Tool::IsClosed(shape)) {
+                        BRepBuilderAPI_MakeSolid s(TopoDS::Shell(shape));
+                        if (s.IsDone()) {
+                            TopoDS_Solid solid = s.Solid();
+                            BRepLib::OrientClosedSolid(solid);
+                            shape = solid;
+                        }
+                    }
+                    for (Standard_Integer i = 1; i <= b.NbFreeEdges(); i++) {
+                        (*fe)->Append(b.FreeEdge(i));
+                    }
+                    for (Standard_Integer i = 1; i <= b.NbMultipleEdges(); i++) {
+                        (*me)->Append(b.MultipleEdge(i));
+                    }
+                    for (Standard_Integer i = 1; i <= b.NbDegeneratedShapes(); i++) {
+                        (*de)->Append(b.DegeneratedShape(i));
+                    }
+                    *r = unique_ptr<TopoDS_Shape>(new TopoDS_Shape(shape));
+                    return Standard_True;
+                } catch (const Standard_Failure&) {
+                    return Standard_False;
+                }
+            })
+        };
+
+        if !ok {
+            return Err(SewingError);
+        }
+
+        Ok(SewingOutput {
+            shape: shape.try_into().map_err(|_| SewingError)?,
+            free_edges: free_edges.into_vec(),
+            multiple_edges: multiple_edges.into_vec(),
+            degenerated_edges: degenerated_edges.into_vec(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::fixtures::*;
+
+    #[test]
+    fn sewing() {
+        let s1 = cube([0.0, 0.0, 0.0], 1.0);
+
+        let r1 = Sewing::new(1e-6)
+            .faces(s1.traverse::<Face>())
+            .build()
+            .unwrap();
+        let r2 = Sewing::new(1e-6)
+            .faces(s1.traverse::<Face>().take(5))
+            .build()
+            .unwrap();
+
+        assert!(matches!(r1.shape, TypedShape::Solid(_)));
+        assert!(r1.free_edges.is_empty());
+        assert!(matches!(r2.shape, TypedShape::Shell(_)));
+        assert_eq!(r2.free_edges.len(), 4);
+    }
+}
@@ -1,3 +1,4 @@
+use super::{CompSolid, Compound, Edge, Face, Shell, Solid, Vertex, Wire};
 use crate::{Ref, Transformation};
 use core::{marker::PhantomData, mem::size_of};
 use cpp::{cpp, cpp_class};
@@ -10,6 +11,7 @@ cpp! {{
 
     #include <TopExp.hxx>
     #include <TopTools_ShapeMapHasher.hxx>
+    #include <TopTools_ListOfShape.hxx>
     #include <NCollection_IndexedMap.hxx>
 
     #include <BRepTools.hxx>
@@ -85,6 +87,73 @@ enum_impls! {
     }
 }
 
+/// The shape of known type
+#[derive(Clone, Debug)]
+pub enum TypedShape {
+    /// A group of shapes.
+    Compound(Compound),
+    /// A set of connected solids.
+    CompSolid(CompSolid),
+    /// A part of 3D space bounded by shells.
+    Solid(Solid),
+    /// A set of connected faces.
+    Shell(Shell),
+    /// A bounded part of surface.
+    Face(Face),
+    /// A sequence of connected edges.
+    Wire(Wire),
+    /// A bounded part of curve.
+    Edge(Edge),
+    /// A point.
+    Vertex(Vertex),
+}
+
+impl TryFrom<Shape> for TypedShape {
+    type Error = ();
+    fn try_from(shape: Shape) -> Result<Self, Self::Error> {
+        Ok(match shape.type_().ok_or(())? {
+            ShapeType::Compound => Self::Compound(shape.try_into()?),
+            ShapeType::CompSolid => Self::CompSolid(shape.try_into()?),
+            ShapeType::Solid => Self::Solid(shape.try_into()?),
+            ShapeType::Shell => Self::Shell(shape.try_into()?),
+            ShapeType::Face => Self::Face(shape.try_into()?),
+            ShapeType::Wire => Self::Wire(shape.try_into()?),
+            ShapeType::Edge => Self::Edge(shape.try_into()?),
+            ShapeType::Vertex => Self::Vertex(shape.try_into()?),
+        })
+    }
+}
+
+impl From<TypedShape> for Shape {
+    fn from(shape: TypedShape) -> Self {
+        match shape {
+            TypedShape::Compound(shape) => shape.into(),
+            TypedShape::CompSolid(shape) => shape.into(),
+            TypedShape::Solid(shape) => shape.into(),
+            TypedShape::Shell(shape) => shape.into(),
+            TypedShape::Face(shape) => shape.into(),
+            TypedShape::Wire(shape) => shape.into(),
+            TypedShape::Edge(shape) => shape.into(),
+            TypedShape::Vertex(shape) => shape.into(),
+        }
+    }
+}
+
+impl AsRef<Shape> for TypedShape {
+    fn as_ref(&self) -> &Shape {
+        match self {
+            TypedShape::Compound(shape) => shape,
+            TypedShape::CompSolid(shape) => shape,
+            TypedShape::Solid(shape) => shape,
+            TypedShape::Shell(shape) => shape,
+            TypedShape::Face(shape) => shape,
+            TypedShape::Wire(shape) => shape,
+            TypedShape::Edge(shape) => shape,
+            TypedShape::Vertex(shape) => shape,
+        }
+    }
+}
+
 impl PartialEq for Shape {
     fn eq(&self, other: &Self) -> bool {
         unsafe {
@@ -95,6 +164,29 @@ impl PartialEq for Shape {
     }
 }
 
+impl Clone for Shape {
+    fn clone(&self) -> Self {
+        Self(unsafe {
+            cpp!([self as "const unique_ptr<TopoDS_Shape>*"] -> ShapePtr as "unique_ptr<TopoDS_Shape>" {
+                return unique_ptr<TopoDS_Shape>(new TopoDS_Shape(**self));
+            })
+        })
+    }
+}
+
+impl core::fmt::Debug for Shape {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        if let Some(type_) = self.type_() {
+            f.debug_struct("Shape")
+                .field("type", &type_)
+                .field("orientation", &self.orientation())
+                .finish()
+        } else {
+            f.write_str("Shape(Null)")
+        }
+    }
+}
+
 impl Shape {
     pub fn is_null(&self) -> bool {
         unsafe {
@@ -104,6 +196,16 @@ impl Shape {
         }
     }
 
+    /// Check whether shapes have the same topology regardless of orientation
+    pub fn is_same(&self, other: impl AsRef<Shape>) -> bool {
+        let other = other.as_ref();
+        unsafe {
+            cpp!([self as "const unique_ptr<TopoDS_Shape>*", other as "const unique_ptr<TopoDS_Shape>*"] -> bool as "Standard_Boolean" {
+                return (*self)->IsSame(**other);
+            })
+        }
+    }
+
     /// Get the type of shape
     pub fn type_(&self) -> Option<ShapeType> {
         if self.is_null() {
@@ -267,3 +369,76 @@ impl IndexedMapOfShape {
         }) as _
     }
 }
+
+cpp_class!(pub(crate) unsafe struct ListOfShape as "unique_ptr<TopTools_ListOfShape>");
+
+impl ListOfShape {
+    pub(crate) fn new() -> Self {
+        unsafe {
+            cpp!([] -> ListOfShape as "unique_ptr<TopTools_ListOfShape>" {
+                return unique_ptr<TopTools_ListOfShape>(new TopTools_ListOfShape());
+            })
+        }
+    }
+
+    pub(crate) fn push(&mut self, shape: &Shape) {
+        unsafe {
+            cpp!([self as "unique_ptr<TopTools_ListOfShape>*", shape as "const unique_ptr<TopoDS_Shape>*"] {
+                (*self)->Append(**shape);
+            })
+        }
+    }
+
+    pub(crate) fn len(&self) -> usize {
+        (unsafe {
+            cpp!([self as "const unique_ptr<TopTools_ListOfShape>*"] -> i32 as "Standard_Integer" {
+                return (*self)->Size();
+            })
+        }) as _
+    }
+
+    pub(crate) fn is_empty(&self) -> bool {
+        unsafe {
+            cpp!([self as "const unique_ptr<TopTools_ListOfShape>*"] -> bool as "Standard_Boolean" {
+                return (*self)->IsEmpty();
+            })
+        }
+    }
+
+    fn pop(&mut self) -> Option<Shape> {
+        let mut shape = Shape::default();
+        let r = &mut shape;
+        let ok = unsafe {
+            cpp!([self as "unique_ptr<TopTools_ListOfShape>*", r as "unique_ptr<TopoDS_Shape>*"] -> bool as "Standard_Boolean" {
+                if ((*self)->IsEmpty()) {
+                    return Standard_False;
+                }
+                *r = unique_ptr<TopoDS_Shape>(new TopoDS_Shape((*self)->First()));
+                (*self)->RemoveFirst();
+                return Standard_True;
+            })
+        };
+        ok.then_some(shape)
+    }
+
+    /// Take the shapes of given type out of list
+    pub(crate) fn into_vec<T: TryFrom<Shape>>(mut self) -> Vec<T> {
+        let mut shapes = Vec::with_capacity(self.len());
+        while let Some(shape) = self.pop() {
+            if let Ok(shape) = shape.try_into() {
+                shapes.push(shape);
+            }
+        }
+        shapes
+    }
+}
+
+impl<T: AsRef<Shape>> FromIterator<T> for ListOfShape {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut list = Self::new();
+        for shape in iter {
+            list.push(shape.as_ref());
+        }
+        list
+    }
+}
@@ -11,6 +11,7 @@ cpp! {{
     #include <TopoDS_Shape.hxx>
     #include <TopoDS_Shell.hxx>
 
+    #include <BRep_Tool.hxx>
     //#include <BRepBuilderAPI_MakeShell.hxx>
 
     using namespace std;
@@ -26,3 +27,14 @@ const_assert_eq!(size_of::<Shell>(), size_of::<*const u8>());
 shape_impls! {
     Shell;
 }
+
+impl Shell {
+    /// Check whether shell has no free edges
+    pub fn is_closed(&self) -> bool {
+        unsafe {
+            cpp!([self as "const unique_ptr<TopoDS_Shell>*"] -> bool as "Standard_Boolean" {
+                return BRep_Tool::IsClosed(**self);
+            })
+        }
+    }
+}
@@ -1,4 +1,7 @@
-use super::{IsShape, Shape};
+use super::{
+    Angle, AngleUnits, Axis3, Edge, Face, IsShape, ListOfShape, Shape, Shell, SweepError,
+    SweepMode, Wire,
+};
 use core::mem::size_of;
 use cpp::{cpp, cpp_class};
 use static_assertions::const_assert_eq;
@@ -7,15 +10,57 @@ cpp! {{
     #include <memory>
 
     #include <gp_Pnt.hxx>
+    #include <gp_Ax2.hxx>
+
+    #include <Standard_Failure.hxx>
 
     #include <TopoDS_Shape.hxx>
+    #include <TopoDS.hxx>
     #include <TopoDS_Solid.hxx>
+    #include <TopoDS_Shell.hxx>
+    #include <TopoDS_Iterator.hxx>
+    #include <TopTools_ListOfShape.hxx>
 
-    //#include <BRepBuilderAPI_MakeSolid.hxx>
+    #include <BRepLib.hxx>
+    #include <BRepBuilderAPI_MakeSolid.hxx>
+    #include <BRepPrimAPI_MakeBox.hxx>
+    #include <BRepPrimAPI_MakeCylinder.hxx>
+    #include <BRepPrimAPI_MakeCone.hxx>
+    #include <BRepPrimAPI_MakeSphere.hxx>
+    #include <BRepPrimAPI_MakeTorus.hxx>
+    #include <BRepPrimAPI_MakeWedge.hxx>
 
     using namespace std;
+
+    static Standard_Boolean orient_shell(const TopoDS_Shell& shell, TopoDS_Shell& oriented) {
+        BRepBuilderAPI_MakeSolid b(shell);
+        if (!b.IsDone()) {
+            return Standard_False;
+        }
+        TopoDS_Solid solid = b.Solid();
+        if (!BRepLib::OrientClosedSolid(solid)) {
+            return Standard_False;
+        }
+        TopoDS_Iterator it(solid);
+        oriented = TopoDS::Shell(it.Value());
+        return Standard_True;
+    }
 }}
 
+enum_impls! {
+    /// Solid construction error
+    SolidError {
+        /// Some of the given dimensions is zero, negative or inconsistent with others.
+        InvalidDimensions = 1,
+        /// The given angle is zero, negative or greater than full turn.
+        AngleOutOfRange,
+        /// The algorithm failed to build the solid.
+        ConstructionFailed,
+        /// Some of the given shells is not closed.
+        NotClosed,
+    }
+}
+
 #[repr(transparent)]
 pub struct Solid(SolidPtr);
 
@@ -26,3 +71,400 @@ const_assert_eq!(size_of::<Solid>(), size_of::<*const u8>());
 shape_impls! {
     Solid;
 }
+
+const FULL_TURN: f64 = 2.0 * core::f64::consts::PI;
+const ANGLE_EPSILON: f64 = 1e-12;
+
+fn check_dimension(value: f64) -> Result<(), SolidError> {
+    if value > 0.0 && value.is_finite() {
+        Ok(())
+    } else {
+        Err(SolidError::InvalidDimensions)
+    }
+}
+
+fn check_angle(angle: &Angle) -> Result<f64, SolidError> {
+    let rad = *angle.to(AngleUnits::Rad).raw();
+    if rad > 0.0 && rad <= FULL_TURN + ANGLE_EPSILON {
+        Ok(rad.min(FULL_TURN))
+    } else {
+        Err(SolidError::AngleOutOfRange)
+    }
+}
+
+fn check_cone(radius1: f64, radius2: f64) -> Result<(), SolidError> {
+    if radius1 >= 0.0
+        && radius2 >= 0.0
+        && radius1.is_finite()
+        && radius2.is_finite()
+        && radius1 != radius2
+    {
+        Ok(())
+    } else {
+        Err(SolidError::InvalidDimensions)
+    }
+}
+
+impl Solid {
+    /// Make box with the corner in the origin of axis and the sizes along its directions
+    pub fn box_(axis: impl AsRef<Axis3>, dx: f64, dy: f64, dz: f64) -> Result<Self, SolidError> {
+        check_dimension(dx)?;
+        check_dimension(dy)?;
+        check_dimension(dz)?;
+        Self::make_box(axis.as_ref(), dx, dy, dz)
+    }
+
+    fn make_box(axis: &Axis3, dx: f64, dy: f64, dz: f64) -> Result<Self, SolidError> {
+        let mut s = SolidPtr::default();
+        let r = &mut s;
+        let ok = unsafe {
+            cpp!([axis as "const gp_Ax2*", dx as "Standard_Real", dy as "Standard_Real", dz as "Standard_Real", r as "unique_ptr<TopoDS_Solid>*"] -> bool as "Standard_Boolean" {
+                try {
+                    BRepPrimAPI_MakeBox b(*axis, dx, dy, dz);
+                    b.Build();
+                    if (!b.IsDone()) {
+                        return Standard_False;
+                    }
+                    *r = unique_ptr<TopoDS_Solid>(new TopoDS_Solid(b.Solid()));
+                    return Standard_True;
+                } catch (const Standard_Failure&) {
+                    return Standard_False;
+                }
+            })
+        };
+        if ok {
+            Ok(Self(s))
+        } else {
+            Err(SolidError::ConstructionFailed)
+        }
+    }
+
+    /// Make cylinder around the axis with the base in its origin
+    pub fn cylinder(axis: impl AsRef<Axis3>, radius: f64, height: f64) -> Result<Self, SolidError> {
+        check_dimension(radius)?;
+        check_dimension(height)?;
+        Self::make_cylinder(axis.as_ref(), radius, height, FULL_TURN)
+    }
+
+    /// Make the sector of cylinder starting from X direction of axis
+    pub fn cylinder_partial(
+        axis: impl AsRef<Axis3>,
+        radius: f64,
+        height: f64,
+        angle: impl AsRef<Angle>,
+    ) -> Result<Self, SolidError> {
+        check_dimension(radius)?;
+        check_dimension(height)?;
+        let rad = check_angle(angle.as_ref())?;
+        Self::make_cylinder(axis.as_ref(), radius, height, rad)
+    }
+
+    fn make_cylinder(axis: &Axis3, radius: f64, height: f64, rad: f64) -> Result<Self, SolidError> {
+        let mut s = SolidPtr::default();
+        let r = &mut s;
+        let ok = unsafe {
+            cpp!([axis as "const gp_Ax2*", radius as "Standard_Real", height as "Standard_Real", rad as "Standard_Real", r as "unique_ptr<TopoDS_Solid>*"] -> bool as "Standard_Boolean" {
+                try {
+                    BRepPrimAPI_MakeCylinder b(*axis, radius, height, rad);
+                    b.Build();
+                    if (!b.IsDone()) {
+                        return Standard_False;
+                    }
+                    *r = unique_ptr<TopoDS_Solid>(new TopoDS_Solid(b.Solid()));
+                    return Standard_True;
+                } catch (const Standard_Failure&) {
+                    return Standard_False;
+                }
+            })
+        };
+        if ok {
+            Ok(Self(s))
+        } else {
+            Err(SolidError::ConstructionFailed)
+        }
+    }
+
+    /// Make cone around the axis with the bottom radius in its origin and the top radius at the height
+    ///
+    /// One of radiuses can be zero but not both.
+    pub fn cone(
+        axis: impl AsRef<Axis3>,
+        radius1: f64,
+        radius2: f64,
+        height: f64,
+    ) -> Result<Self, SolidError> {
+        check_cone(radius1, radius2)?;
+        check_dimension(height)?;
+        Self::make_cone(axis.as_ref(), radius1, radius2, height, FULL_TURN)
+    }
+
+    /// Make the sector of cone starting from X direction of axis
+    pub fn cone_partial(
+        axis: impl AsRef<Axis3>,
+        radius1: f64,
+        radius2: f64,
+        height: f64,
+        angle: impl AsRef<Angle>,
+    ) -> Result<Self, SolidError> {
+        check_cone(radius1, radius2)?;
+        check_dimension(height)?;
+        let rad = check_angle(angle.as_ref())?;
+        Self::make_cone(axis.as_ref(), radius1, radius2, height, rad)
+    }
+
+    fn make_cone(
+        axis: &Axis3,
+        radius1: f64,
+        radius2: f64,
+        height: f64,
+        rad: f64,
+    ) -> Result<Self, SolidError> {
+        let mut s = SolidPtr::default();
+        let r = &mut s;
+        let ok = unsafe {
+            cpp!([axis as "const gp_Ax2*", radius1 as "Standard_Real", radius2 as "Standard_Real", height as "Standard_Real", rad as "Standard_Real", r as "unique_ptr<TopoDS_Solid>*"] -> bool as "Standard_Boolean" {
+                try {
+                    BRepPrimAPI_MakeCone b(*axis, radius1, radius2, height, rad);
+                    b.Build();
+                    if (!b.IsDone()) {
+                        return Standard_False;
+                    }
+                    *r = unique_ptr<TopoDS_Solid>(new TopoDS_Solid(b.Solid()));
+                    return Standard_True;
+                } catch (const Standard_Failure&) {
+                    return Standard_False;
+                }
+            })
+        };
+        if ok {
+            Ok(Self(s))
+        } else {
+            Err(SolidError::ConstructionFailed)
+        }
+    }
+
+    /// Make sphere with the center in the origin of axis
+    pub fn sphere(axis: impl AsRef<Axis3>, radius: f64) -> Result<Self, SolidError> {
+        check_dimension(radius)?;
+        Self::make_sphere(axis.as_ref(), radius, FULL_TURN)
+    }
+
+    /// Make the sector of sphere starting from X direction of axis
+    pub fn sphere_partial(
+        axis: impl AsRef<Axis3>,
+        radius: f64,
+        angle: impl AsRef<Angle>,
+    ) -> Result<Self, SolidError> {
+        check_dimension(radius)?;
+        let rad = check_angle(angle.as_ref())?;
+        Self::make_sphere(axis.as_ref(), radius, rad)
+    }
+
+    fn make_sphere(axis: &Axis3, radius: f64, rad: f64) -> Result<Self, SolidError> {
+        let mut s = SolidPtr::default();
+        let r = &mut s;
+        let ok = unsafe {
+            cpp!([axis as "const gp_Ax2*", radius as "Standard_Real", rad as "Standard_Real", r as "unique_ptr<TopoDS_Solid>*"] -> bool as "Standard_Boolean" {
+                try {
+                    BRepPrimAPI_MakeSphere b(*axis, radius, rad);
+                    b.Build();
+                    if (!b.IsDone()) {
+                        return Standard_False;
+                    }
+                    *r = unique_ptr<TopoDS_Solid>(new TopoDS_Solid(b.Solid()));
+                    return Standard_True;
+                } catch (const Standard_Failure&) {
+                    return Standard_False;
+                }
+            })
+        };
+        if ok {
+            Ok(Self(s))
+        } else {
+            Err(SolidError::ConstructionFailed)
+        }
+    }
+
+    /// Make torus around the axis with the center in its origin
+    ///
+    /// The first radius is the distance from the center to the center of tube, the second radius is the radius of tube.
+    pub fn torus(axis: impl AsRef<Axis3>, radius1: f64, radius2: f64) -> Result<Self, SolidError> {
+        check_dimension(radius1)?;
+        check_dimension(radius2)?;
+        Self::make_torus(axis.as_ref(), radius1, radius2, FULL_TURN)
+    }
+
+    /// Make the sector of torus starting from X direction of axis
+    pub fn torus_partial(
+        axis: impl AsRef<Axis3>,
+        radius1: f64,
+        radius2: f64,
+        angle: impl AsRef<Angle>,
+    ) -> Result<Self, SolidError> {
+        check_dimension(radius1)?;
+        check_dimension(radius2)?;
+        let rad = check_angle(angle.as_ref())?;
+        Self::make_torus(axis.as_ref(), radius1, radius2, rad)
+    }
+
+    fn make_torus(axis: &Axis3, radius1: f64, radius2: f64, rad: f64) -> Result<Self, SolidError> {
+        let mut s = SolidPtr::default();
+        let r = &mut s;
+        let ok = unsafe {
+            cpp!([axis as "const gp_Ax2*", radius1 as "Standard_Real", radius2 as "Standard_Real", rad as "Standard_Real", r as "unique_ptr<TopoDS_Solid>*"] -> bool as "Standard_Boolean" {
+                try {
+                    BRepPrimAPI_MakeTorus b(*axis, radius1, radius2, rad);
+                    b.Build();
+                    if (!b.IsDone()) {
+                        return Standard_False;
+                    }
+                    *r = unique_ptr<TopoDS_Solid>(new TopoDS_Solid(b.Solid()));
+                    return Standard_True;
+                } catch (const Standard_Failure&) {
+                    return Standard_False;
+                }
+            })
+        };
+        if ok {
+            Ok(Self(s))
+        } else {
+            Err(SolidError::ConstructionFailed)
+        }
+    }
+
+    /// Make wedge with the corner in the origin of axis
+    ///
+    /// The wedge is a box which top face (at `dy`) has the size `ltx` along X direction instead of `dx`.
+    pub fn wedge(
+        axis: impl AsRef<Axis3>,
+        dx: f64,
+        dy: f64,
+        dz: f64,
+        ltx: f64,
+    ) -> Result<Self, SolidError> {
+        check_dimension(dx)?;
+        check_dimension(dy)?;
+        check_dimension(dz)?;
+        if ltx < 0.0 || !ltx.is_finite() {
+            return Err(SolidError::InvalidDimensions);
+        }
+        Self::make_wedge(axis.as_ref(), dx, dy, dz, ltx)
+    }
+
+    fn make_wedge(axis: &Axis3, dx: f64, dy: f64, dz: f64, ltx: f64) -> Result<Self, SolidError> {
+        let mut s = SolidPtr::default();
+        let r = &mut s;
+        let ok = unsafe {
+            cpp!([axis as "const gp_Ax2*", dx as "Standard_Real", dy as "Standard_Real", dz as "Standard_Real", ltx as "Standard_Real", r as "unique_ptr<TopoDS_Solid>*"] -> bool as "Standard_Boolean" {
+                try {
+                    BRepPrimAPI_MakeWedge b(*axis, dx, dy, dz, ltx);
+                    b.Build();
+                    if (!b.IsDone()) {
+                        return Standard_False;
+                    }
+                    *r = unique_ptr<TopoDS_Solid>(new TopoDS_Solid(b.Solid()));
+                    return Standard_True;
+                } catch (const Standard_Failure&) {
+                    return Standard_False;
+                }
+            })
+        };
+        if ok {
+            Ok(Self(s))
+        } else {
+            Err(SolidError::ConstructionFailed)
+        }
+    }
+
+    /// Make solid bounded by outer shell with inner voids
+    ///
+    /// The shells are oriented so that the matter lies inside the outer shell and outside the voids.
+    pub fn from_shells<T: AsRef<Shell>>(
+        outer: impl AsRef<Shell>,
+        voids: impl IntoIterator<Item = T>,
+    ) -> Result<Self, SolidError> {
+        let outer = outer.as_ref();
+        if !outer.is_closed() {
+            return Err(SolidError::NotClosed);
+        }
+
+        let mut inner = ListOfShape::new();
+        for void in voids {
+            let void = void.as_ref();
+            if !void.is_closed() {
+                return Err(SolidError::NotClosed);
+            }
+            inner.push(void);
+        }
+        let inner = &inner;
+
+        let mut s = SolidPtr::default();
+        let r = &mut s;
+        let ok = unsafe {
+            cpp!([outer as "const unique_ptr<TopoDS_Shell>*", inner as "const unique_ptr<TopTools_ListOfShape>*", r as "unique_ptr<TopoDS_Solid>*"] -> bool as "Standard_Boolean" {
+                try {
+                    TopoDS_Shell shell;
+                    if (!orient_shell(**outer, shell)) {
+                        return Standard_False;
+                    }
+                    BRepBuilderAPI_MakeSolid b(shell);
+                    for (TopTools_ListOfShape::Iterator it(**inner); it.More(); it.Next()) {
+                        if (!orient_shell(TopoDS::Shell(it.Value()), shell)) {
+                            return Standard_False;
+                        }
+                        b.Add(TopoDS::Shell(shell.Reversed()));
+                    }
+                    if (!b.IsDone()) {
+                        return Standard_False;
+                    }
+                    *r = unique_ptr<TopoDS_Solid>(new TopoDS_Solid(b.Solid()));
+                    return Standard_True;
+                } catch (const Standard_Failure&) {
+                    return Standard_False;
+                }
+            })
+        };
+        if ok {
+            Ok(Self(s))
+        } else {
+            Err(SolidError::ConstructionFailed)
+        }
+    }
+
+    /// Make threaded rod by sweeping profile along helix around the axis
+    ///
+    /// The profile is a planar face which lies in the plane of axis and its X direction.
+    /// The radius of helix is the distance from the axis to the centroid of profile.
+    /// The swept ridge is fused with the core cylinder of the same radius and length,
+    /// so the part of profile inside this radius is absorbed by the core.
+    pub fn thread(
+        profile: impl AsRef<Face>,
+        axis: impl AsRef<Axis3>,
+        pitch: f64,
+        length: f64,
+    ) -> Result<Self, SweepError> {
+        let profile = profile.as_ref();
+        let axis = axis.as_ref();
+
+        let center = profile.surface_properties().center_of_mass;
+        let origin = axis.axis.point;
+        let dir = axis.axis.dir;
+        let dx = center.x - origin.x;
+        let dy = center.y - origin.y;
+        let dz = center.z - origin.z;
+        let along = (dx * dir.x + dy * dir.y + dz * dir.z)
+            / (dir.x * dir.x + dir.y * dir.y + dir.z * dir.z).sqrt();
+        let radius = (dx * dx + dy * dy + dz * dz - along * along)
+            .max(0.0)
+            .sqrt();
+
+        let helix = Edge::helix(axis, radius, pitch, length, false).map_err(|_| SweepError)?;
+        let spine = Wire::from_edges([&helix]).map_err(|_| SweepError)?;
+        let ridge = profile.sweep(&spine, SweepMode::FixedBinormal(dir))?;
+        let core = Self::cylinder(axis, radius, length).map_err(|_| SweepError)?;
+
+        let shape = ridge.fuse(&core).map_err(|_| SweepError)?;
+        let solid = shape.traverse::<Self>().next().ok_or(SweepError)?;
+        Ok(Self::clone(&solid))
+    }
+}
@@ -0,0 +1,267 @@
+use super::{Face, ListOfShape, Shape, Shell, Solid, TypedShape, Vector, Wire};
+use cpp::cpp;
+
+cpp! {{
+    #include <memory>
+
+    #include <Standard_Failure.hxx>
+
+    #include <gp_Vec.hxx>
+    #include <gp_Dir.hxx>
+
+    #include <TopoDS.hxx>
+    #include <TopoDS_Shape.hxx>
+    #include <TopoDS_Wire.hxx>
+    #include <TopoDS_Face.hxx>
+    #include <TopoDS_Solid.hxx>
+    #include <TopoDS_Iterator.hxx>
+    #include <TopTools_ListOfShape.hxx>
+
+    #include <BRepTools.hxx>
+    #include <BRepLib.hxx>
+    #include <BRepOffsetAPI_MakePipeShell.hxx>
+
+    using namespace std;
+}}
+
+/// Sweep construction error
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SweepError;
+
+/// The orientation of profile along spine
+#[derive(Clone, Debug, Default)]
+pub enum SweepMode {
+    /// The profile follows the Frenet trihedron of spine.
+    Frenet,
+    /// The profile follows the Frenet trihedron corrected to minimize twisting.
+    #[default]
+    CorrectedFrenet,
+    /// The profile keeps the given binormal direction.
+    FixedBinormal(Vector),
+    /// The normal of profile points to the auxiliary spine.
+    AuxiliarySpine(Wire),
+}
+
+/// Sweep of one or many profiles along spine
+pub struct Sweep {
+    spine: Wire,
+    mode: SweepMode,
+    profiles: ListOfShape,
+    make_solid: bool,
+}
+
+impl Sweep {
+    /// Create sweep along given spine
+    pub fn new(spine: impl AsRef<Wire>) -> Self {
+        Self {
+            spine: spine.as_ref().clone(),
+            mode: SweepMode::default(),
+            profiles: ListOfShape::new(),
+            make_solid: false,
+        }
+    }
+
+    /// Set the orientation mode of profiles
+    pub fn mode(mut self, mode: SweepMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    /// Add profile
+    ///
+    /// The profile is a wire or a vertex. The profiles are placed at the points of spine
+    /// which are nearest to them.
+    pub fn profile(mut self, profile: impl AsRef<Shape>) -> Self {
+        self.profiles.push(profile.as_ref());
+        self
+    }
+
+    /// Add profiles
+    pub fn profiles<T: AsRef<Shape>>(mut self, profiles: impl IntoIterator<Item = T>) -> Self {
+        for profile in profiles {
+            self.profiles.push(profile.as_ref());
+        }
+        self
+    }
+
+    /// Close the ends to make solid
+    pub fn make_solid(mut self, flag: bool) -> Self {
+        self.make_solid = flag;
+        self
+    }
+
+    /// Perform sweep
+    pub fn build(&self) -> Result<TypedShape, SweepError> {
+        if self.profiles.is_empty() {
+            return Err(SweepError);
+        }
+
+        let spine = &self.spine;
+        let profiles = &self.profiles;
+        let make_solid = self.make_solid;
+        let null = Shape::default();
+        let (kind, binormal, auxiliary): (u32, Vector, &Shape) = match &self.mode {
+            SweepMode::Frenet => (0, Vector::default(), &null),
+            SweepMode::CorrectedFrenet => (1, Vector::default(), &null),
+            SweepMode::FixedBinormal(binormal) => (2, *binormal, &null),
+            SweepMode::AuxiliarySpine(auxiliary) => (3, Vector::default(), auxiliary),
+        };
+        let binormal = &binormal;
+
+        let mut shape = Shape::default();
+        let r = &mut shape;
+
+        let ok = unsafe {
+            cpp!([spine as "const unique_ptr<TopoDS_Wire>*", profiles as "const unique_ptr<TopTools_ListOfShape>*", make_solid as "Standard_Boolean", kind as "uint32_t", binormal as "const gp_Vec*", auxiliary as "const unique_ptr<TopoDS_Shape>*", r as "unique_ptr<TopoDS_Shape>*"] -> bool as "Standard_Boolean" {
+                try {
+                    BRepOffsetAPI_MakePipeShell b(**spine);
+                    switch (kind) {
+                    case 0:
+                        b.SetMode(Standard_True);
+                        break;
+                    case 1:
+                        b.SetMode(Standard_False);
+                        break;
+                    case 2:
+                        b.SetMode(gp_Dir(*binormal));
+                        break;
+                    default:
+                        b.SetMode(TopoDS::Wire(**auxiliary), Standard_True);
+                        break;
+                    }
+                    for (TopTools_ListOfShape::Iterator it(**profiles); it.More(); it.Next()) {
+                        b.Add(it.Value());
+                    }
+                    b.Build();
+                    if (!b.IsDone()) {
+                        return Standard_False;
+                    }
+                    if (make_solid && !b.MakeSolid()) {
+                        return Standard_False;
+                    }
+                    TopoDS_Shape shape = b.Shape();
+                    if (shape.ShapeType() == TopAbs_SOLID) {
+                        TopoDS_Solid solid = TopoDS::Solid(shape);
+                        BRepLib::OrientClosedSolid(solid);
+                        shape = solid;
+                    }
+                    *r = unique_ptr<TopoDS_Shape>(new TopoDS_Shape(shape));
+                    return Standard_True;
+                } catch (const Standard_Failure&) {
+                    return Standard_False;
+                }
+            })
+        };
+
+        if ok {
+            shape.try_into().map_err(|_| SweepError)
+        } else {
+            Err(SweepError)
+        }
+    }
+}
+
+impl Wire {
+    /// Sweep wire along spine to make shell
+    ///
+    /// The simple pipe algorithm is not used for single profile, since it supports
+    /// neither fixed binormal nor auxiliary spine modes.
+    pub fn sweep(&self, spine: impl AsRef<Wire>, mode: SweepMode) -> Result<Shell, SweepError> {
+        match Sweep::new(spine).mode(mode).profile(self).build()? {
+            TypedShape::Shell(shell) => Ok(shell),
+            _ => Err(SweepError),
+        }
+    }
+}
+
+impl Face {
+    /// Sweep face along spine to make solid
+    ///
+    /// The holes of face are swept too and cut from the resulting solid.
+    pub fn sweep(&self, spine: impl AsRef<Wire>, mode: SweepMode) -> Result<Solid, SweepError> {
+        let spine = spine.as_ref();
+        let mut wires = self.wires().into_iter();
+        let outer = wires.next().ok_or(SweepError)?;
+
+        let mut solid: Shape = Self::sweep_wire(&outer, spine, &mode)?.into();
+        for inner in wires {
+            let inner = Self::sweep_wire(&inner, spine, &mode)?;
+            solid = solid.cut(inner).map_err(|_| SweepError)?;
+        }
+
+        let solid = solid.traverse::<Solid>().next().ok_or(SweepError)?;
+        Ok(Solid::clone(&solid))
+    }
+
+    fn sweep_wire(wire: &Wire, spine: &Wire, mode: &SweepMode) -> Result<Solid, SweepError> {
+        match Sweep::new(spine)
+            .mode(mode.clone())
+            .profile(wire)
+            .make_solid(true)
+            .build()?
+        {
+            TypedShape::Solid(solid) => Ok(solid),
+            _ => Err(SweepError),
+        }
+    }
+
+    /// Get the outer wire followed by the inner wires
+    fn wires(&self) -> Vec<Wire> {
+        let mut wires = ListOfShape::new();
+        let r = &mut wires;
+        unsafe {
+            cpp!([self as "const unique_ptr<TopoDS_Face>*", r as "unique_ptr<TopTools_ListOfShape>*"] {
+                TopoDS_Wire outer = BRepTools::OuterWire(**self);
+                if (outer.IsNull()) {
+                    return;
+                }
+                (*r)->Append(outer);
+                for (TopoDS_Iterator it(**self); it.More(); it.Next()) {
+                    if (it.Value().ShapeType() == TopAbs_WIRE && !it.Value().IsSame(outer)) {
+                        (*r)->Append(it.Value());
+                    }
+                }
+            })
+        };
+        wires.into_vec()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::Edge;
+    use super::*;
+    use crate::fixtures::*;
+
+    fn spine() -> Wire {
+        polyline(&[[0.0, 0.0, 0.0], [0.0, 0.0, 2.0]])
+    }
+
+    #[test]
+    fn sweep() {
+        let w1 = square(0.0, 1.0);
+        let h1 = w1.sweep(spine(), SweepMode::CorrectedFrenet).unwrap();
+
+        assert_eq!(h1.traverse::<Face>().count(), 4);
+
+        let f1 = Face::from_wires([&w1]).unwrap();
+        let m1 = SweepMode::FixedBinormal(Vector::new(1.0, 0.0, 0.0));
+        let s1 = f1.sweep(spine(), m1).unwrap();
+
+        assert!((s1.volume() - 8.0).abs() < 1e-6);
+
+        let a1 = axis([0.0, 0.0, 0.0]);
+        let c1 = Wire::from_edges([Edge::circle(&a1, 0.5).unwrap()]).unwrap();
+        let r1 = Sweep::new(spine())
+            .profiles([&square(0.0, 1.0), &square(2.0, 0.5)])
+            .make_solid(true)
+            .build()
+            .unwrap();
+        let r2 = Sweep::new(spine()).build();
+        let r3 = c1.sweep(spine(), SweepMode::Frenet).unwrap();
+
+        assert!(matches!(r1, TypedShape::Solid(_)));
+        assert_eq!(r2.err(), Some(SweepError));
+        assert_eq!(r3.traverse::<Face>().count(), 1);
+    }
+}
@@ -0,0 +1,186 @@
+mod brep;
+mod iges;
+mod step;
+mod stl;
+
+pub use brep::*;
+pub use iges::*;
+pub use step::*;
+pub use stl::*;
+
+use crate::{message::Messages, Shape};
+use cpp::cpp;
+use std::{ffi::CString, path::Path};
+
+cpp! {{
+    #include <memory>
+    #include <string>
+    #include <vector>
+
+    #include <Standard_Failure.hxx>
+
+    #include <TopoDS_Shape.hxx>
+
+    #include <Interface_Check.hxx>
+    #include <Interface_CheckIterator.hxx>
+    #include <Transfer_TransientProcess.hxx>
+    #include <XSControl_Reader.hxx>
+    #include <XSControl_WorkSession.hxx>
+    #include <XSControl_TransferReader.hxx>
+
+    #include <STEPControl_Reader.hxx>
+    #include <IGESControl_Reader.hxx>
+
+    using namespace std;
+}}
+
+/// Data exchange error
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ExchangeError {
+    /// The path is not valid UTF-8 string or contains NUL character.
+    InvalidPath,
+    /// The file cannot be read or has wrong format.
+    ReadFailed,
+    /// The file does not contain transferable shapes.
+    NoShapes,
+    /// The shape cannot be transferred to the file model.
+    TransferFailed,
+    /// The file cannot be written.
+    WriteFailed,
+    /// The input or output operation failed.
+    Io(std::io::ErrorKind),
+}
+
+/// Length units of exchange files
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub enum LengthUnit {
+    /// Inches
+    Inch,
+    /// Millimeters
+    #[default]
+    Millimeter,
+    /// Feet
+    Foot,
+    /// Miles
+    Mile,
+    /// Meters
+    Meter,
+    /// Kilometers
+    Kilometer,
+    /// Thousandths of inch
+    Mil,
+    /// Micrometers
+    Micrometer,
+    /// Centimeters
+    Centimeter,
+    /// Millionths of inch
+    Microinch,
+}
+
+impl LengthUnit {
+    /// The name of unit used by exchange formats
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Inch => "INCH",
+            Self::Millimeter => "MM",
+            Self::Foot => "FT",
+            Self::Mile => "MI",
+            Self::Meter => "M",
+            Self::Kilometer => "KM",
+            Self::Mil => "MIL",
+            Self::Micrometer => "UM",
+            Self::Centimeter => "CM",
+            Self::Microinch => "UIN",
+        }
+    }
+
+    fn to_cstring(self) -> CString {
+        CString::new(self.name()).unwrap()
+    }
+}
+
+/// The result of importing shapes
+pub struct ImportOutput {
+    /// The imported shape
+    pub shape: Shape,
+    /// The number of transferred roots
+    pub roots: usize,
+    /// The warnings reported while transfer
+    pub warnings: Vec<String>,
+}
+
+fn path_to_cstring(path: &Path) -> Result<CString, ExchangeError> {
+    path.to_str()
+        .and_then(|path| CString::new(path).ok())
+        .ok_or(ExchangeError::InvalidPath)
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Format {
+    Step,
+    Iges,
+}
+
+fn read_file(path: &Path, format: Format) -> Result<ImportOutput, ExchangeError> {
+    let path = path_to_cstring(path)?;
+    let path = path.as_ptr();
+    let iges = format == Format::Iges;
+
+    let mut shape = Shape::default();
+    let r = &mut shape;
+    let mut roots = 0i32;
+    let n = &mut roots;
+    let mut warnings = Messages::new();
+    let w = &mut warnings;
+
+    let rc = unsafe {
+        cpp!([path as "const char*", iges as "Standard_Boolean", r as "unique_ptr<TopoDS_Shape>*", n as "Standard_Integer*", w as "unique_ptr<vector<string>>*"] -> u32 as "uint32_t" {
+            unique_ptr<XSControl_Reader> reader;
+            try {
+                if (iges) {
+                    reader.reset(new IGESControl_Reader());
+                } else {
+                    reader.reset(new STEPControl_Reader());
+                }
+                if (reader->ReadFile(path) != IFSelect_RetDone) {
+                    return 1;
+                }
+            } catch (const Standard_Failure&) {
+                return 1;
+            }
+            try {
+                *n = reader->TransferRoots();
+                Handle(Transfer_TransientProcess) process = reader->WS()->TransferReader()->TransientProcess();
+                if (!process.IsNull()) {
+                    Interface_CheckIterator checks = process->CheckList(Standard_False);
+                    for (checks.Start(); checks.More(); checks.Next()) {
+                        const Handle(Interface_Check)& check = checks.Value();
+                        for (Standard_Integer i = 1; i <= check->NbWarnings(); i++) {
+                            (*w)->push_back(check->CWarning(i));
+                        }
+                    }
+                }
+                if (*n > 0) {
+                    *r = unique_ptr<TopoDS_Shape>(new TopoDS_Shape(reader->OneShape()));
+                }
+            } catch (const Standard_Failure&) {
+                return 2;
+            }
+            return 0;
+        })
+    };
+
+    if rc == 1 {
+        Err(ExchangeError::ReadFailed)
+    } else if rc != 0 {
+        Err(ExchangeError::TransferFailed)
+    } else if roots == 0 {
+        Err(ExchangeError::NoShapes)
+    } else {
+        Ok(ImportOutput {
+            shape,
+            roots: roots as _,
+            warnings: warnings.to_vec(),
+        })
+    }
+}
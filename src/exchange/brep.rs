@@ -0,0 +1,231 @@
+use super::ExchangeError;
+use crate::Shape;
+use cpp::{cpp, cpp_class};
+use std::{
+    fs::File,
+    io::{Read, Write},
+    path::Path,
+};
+
+cpp! {{
+    #include <memory>
+    #include <string>
+    #include <sstream>
+
+    #include <Standard_Failure.hxx>
+
+    #include <TopoDS_Shape.hxx>
+
+    #include <BRep_Builder.hxx>
+    #include <BRepTools.hxx>
+    #include <BinTools.hxx>
+
+    using namespace std;
+}}
+
+/// Native BRep serialization format
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Default)]
+pub enum BrepFormat {
+    /// Human readable text
+    #[default]
+    Text,
+    /// Compact binary
+    Binary,
+}
+
+cpp_class!(unsafe struct Buffer as "unique_ptr<string>");
+
+impl Buffer {
+    fn as_bytes(&self) -> &[u8] {
+        let ptr = unsafe {
+            cpp!([self as "const unique_ptr<string>*"] -> *const u8 as "const char*" {
+                return (*self)->data();
+            })
+        };
+        let len = unsafe {
+            cpp!([self as "const unique_ptr<string>*"] -> usize as "size_t" {
+                return (*self)->size();
+            })
+        };
+        unsafe { core::slice::from_raw_parts(ptr, len) }
+    }
+}
+
+impl Shape {
+    /// Serialize shape to BRep text
+    pub fn to_brep_string(&self) -> Result<String, ExchangeError> {
+        self.to_brep(BrepFormat::Text)
+            .map(|data| String::from_utf8_lossy(&data).into_owned())
+    }
+
+    /// Deserialize shape from BRep text
+    pub fn from_brep_str(data: &str) -> Result<Shape, ExchangeError> {
+        Self::from_brep(data.as_bytes(), BrepFormat::Text)
+    }
+
+    /// Serialize shape to binary BRep
+    pub fn to_brep_bytes(&self) -> Result<Vec<u8>, ExchangeError> {
+        self.to_brep(BrepFormat::Binary)
+    }
+
+    /// Deserialize shape from binary BRep
+    pub fn from_brep_bytes(data: &[u8]) -> Result<Shape, ExchangeError> {
+        Self::from_brep(data, BrepFormat::Binary)
+    }
+
+    /// Write shape to BRep file
+    pub fn write_brep(
+        &self,
+        path: impl AsRef<Path>,
+        format: BrepFormat,
+    ) -> Result<(), ExchangeError> {
+        let file = File::create(path).map_err(|error| ExchangeError::Io(error.kind()))?;
+        self.write_brep_to(file, format)
+    }
+
+    /// Read shape from BRep file
+    pub fn read_brep(path: impl AsRef<Path>, format: BrepFormat) -> Result<Shape, ExchangeError> {
+        let file = File::open(path).map_err(|error| ExchangeError::Io(error.kind()))?;
+        Self::read_brep_from(file, format)
+    }
+
+    /// Write shape in BRep format using writer
+    pub fn write_brep_to(
+        &self,
+        mut writer: impl Write,
+        format: BrepFormat,
+    ) -> Result<(), ExchangeError> {
+        let data = self.to_brep(format)?;
+        writer
+            .write_all(&data)
+            .and_then(|_| writer.flush())
+            .map_err(|error| ExchangeError::Io(error.kind()))
+    }
+
+    /// Read shape in BRep format using reader
+    pub fn read_brep_from(
+        mut reader: impl Read,
+        format: BrepFormat,
+    ) -> Result<Shape, ExchangeError> {
+        let mut data = Vec::new();
+        reader
+            .read_to_end(&mut data)
+            .map_err(|error| ExchangeError::Io(error.kind()))?;
+        Self::from_brep(&data, format)
+    }
+
+    fn to_brep(&self, format: BrepFormat) -> Result<Vec<u8>, ExchangeError> {
+        let binary = format == BrepFormat::Binary;
+        let mut buffer = unsafe {
+            cpp!([] -> Buffer as "unique_ptr<string>" {
+                return unique_ptr<string>(new string());
+            })
+        };
+        let b = &mut buffer;
+
+        let ok = unsafe {
+            cpp!([self as "const unique_ptr<TopoDS_Shape>*", binary as "Standard_Boolean", b as "unique_ptr<string>*"] -> bool as "Standard_Boolean" {
+                try {
+                    ostringstream stream;
+                    if (binary) {
+                        BinTools::Write(**self, stream);
+                    } else {
+                        BRepTools::Write(**self, stream);
+                    }
+                    **b = stream.str();
+                    return Standard_True;
+                } catch (const Standard_Failure&) {
+                    return Standard_False;
+                }
+            })
+        };
+
+        if ok {
+            Ok(buffer.as_bytes().to_vec())
+        } else {
+            Err(ExchangeError::TransferFailed)
+        }
+    }
+
+    fn from_brep(data: &[u8], format: BrepFormat) -> Result<Shape, ExchangeError> {
+        let binary = format == BrepFormat::Binary;
+        let ptr = data.as_ptr();
+        let len = data.len();
+
+        let mut shape = Shape::default();
+        let r = &mut shape;
+
+        let ok = unsafe {
+            cpp!([ptr as "const char*", len as "size_t", binary as "Standard_Boolean", r as "unique_ptr<TopoDS_Shape>*"] -> bool as "Standard_Boolean" {
+                try {
+                    istringstream stream(string(ptr, len));
+                    if (binary) {
+                        BinTools::Read(**r, stream);
+                    } else {
+                        BRep_Builder builder;
+                        BRepTools::Read(**r, stream, builder);
+                    }
+                    return Standard_True;
+                } catch (const Standard_Failure&) {
+                    return Standard_False;
+                }
+            })
+        };
+
+        if !ok {
+            Err(ExchangeError::ReadFailed)
+        } else if shape.is_null() {
+            Err(ExchangeError::NoShapes)
+        } else {
+            Ok(shape)
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::fixtures::*;
+    use crate::{Face, Solid};
+    use std::io::Cursor;
+
+    #[test]
+    fn brep() {
+        let s1 = Solid::box_(axis([0.0, 0.0, 0.0]), 1.0, 2.0, 3.0).unwrap();
+
+        let t1 = s1.to_brep_string().unwrap();
+        let b1 = s1.to_brep_bytes().unwrap();
+        let r1 = Shape::from_brep_str(&t1).unwrap();
+        let r2 = Shape::from_brep_bytes(&b1).unwrap();
+        let r3 = Shape::from_brep_str("");
+
+        assert_eq!(r1.traverse::<Face>().count(), 6);
+        assert_eq!(r2.traverse::<Face>().count(), 6);
+        assert!(r3.is_err());
+    }
+
+    #[test]
+    fn brep_file() {
+        let s1 = Solid::box_(axis([0.0, 0.0, 0.0]), 1.0, 2.0, 3.0).unwrap();
+        let path = TempFile::new("brep.brep");
+
+        s1.write_brep(&path, BrepFormat::Binary).unwrap();
+        let r1 = Shape::read_brep(&path, BrepFormat::Binary).unwrap();
+        let r2 = Shape::read_brep(path.with_extension("none"), BrepFormat::Binary);
+
+        assert_eq!(r1.traverse::<Face>().count(), 6);
+        assert!(matches!(r2.err(), Some(ExchangeError::Io(_))));
+    }
+
+    #[test]
+    fn brep_io() {
+        let s1 = Solid::box_(axis([0.0, 0.0, 0.0]), 1.0, 2.0, 3.0).unwrap();
+        let mut cursor = Cursor::new(Vec::new());
+
+        s1.write_brep_to(&mut cursor, BrepFormat::Text).unwrap();
+        cursor.set_position(0);
+        let r1 = Shape::read_brep_from(&mut cursor, BrepFormat::Text).unwrap();
+
+        assert_eq!(r1.traverse::<Face>().count(), 6);
+    }
+}
@@ -0,0 +1,117 @@
+use super::{path_to_cstring, read_file, ExchangeError, Format, ImportOutput, LengthUnit};
+use crate::Shape;
+use core::ffi::c_char;
+use cpp::cpp;
+use std::path::Path;
+
+cpp! {{
+    #include <memory>
+
+    #include <Standard_Failure.hxx>
+
+    #include <TopoDS_Shape.hxx>
+
+    #include <IGESControl_Controller.hxx>
+    #include <IGESControl_Writer.hxx>
+
+    using namespace std;
+}}
+
+enum_impls! {
+    /// The representation of shapes in IGES file
+    IgesMode {
+        /// Trimmed surfaces (entities 144 and 143)
+        Faces,
+        /// Boundary representation solids (entity 186)
+        Brep,
+    }
+}
+
+/// IGES writing options
+#[derive(Clone, Copy, Debug)]
+pub struct IgesOptions {
+    /// The representation of shapes
+    pub mode: IgesMode,
+    /// The length unit of file
+    pub unit: LengthUnit,
+}
+
+impl Default for IgesOptions {
+    fn default() -> Self {
+        Self {
+            mode: IgesMode::Faces,
+            unit: LengthUnit::default(),
+        }
+    }
+}
+
+impl Shape {
+    /// Read shape from IGES file
+    pub fn read_iges(path: impl AsRef<Path>) -> Result<ImportOutput, ExchangeError> {
+        read_file(path.as_ref(), Format::Iges)
+    }
+
+    /// Write shape to IGES file
+    pub fn write_iges(
+        &self,
+        path: impl AsRef<Path>,
+        options: &IgesOptions,
+    ) -> Result<(), ExchangeError> {
+        let path = path_to_cstring(path.as_ref())?;
+        let path = path.as_ptr();
+        let mode = options.mode;
+        let unit = options.unit.to_cstring();
+        let unit: *const c_char = unit.as_ptr();
+
+        let rc = unsafe {
+            cpp!([self as "const unique_ptr<TopoDS_Shape>*", path as "const char*", mode as "Standard_Integer", unit as "const char*"] -> u32 as "uint32_t" {
+                try {
+                    IGESControl_Controller::Init();
+                    IGESControl_Writer writer(unit, mode);
+                    try {
+                        if (!writer.AddShape(**self)) {
+                            return 1;
+                        }
+                        writer.ComputeModel();
+                    } catch (const Standard_Failure&) {
+                        return 1;
+                    }
+                    if (!writer.Write(path)) {
+                        return 2;
+                    }
+                    return 0;
+                } catch (const Standard_Failure&) {
+                    return 2;
+                }
+            })
+        };
+
+        match rc {
+            0 => Ok(()),
+            1 => Err(ExchangeError::TransferFailed),
+            _ => Err(ExchangeError::WriteFailed),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::fixtures::*;
+    use crate::{Face, Solid};
+
+    #[test]
+    fn iges() {
+        let s1 = Solid::box_(axis([0.0, 0.0, 0.0]), 1.0, 2.0, 3.0).unwrap();
+        let path = TempFile::new("iges.igs");
+        let options = IgesOptions {
+            mode: IgesMode::Brep,
+            ..Default::default()
+        };
+
+        s1.write_iges(&path, &options).unwrap();
+        let r1 = Shape::read_iges(&path).unwrap();
+
+        assert_eq!(r1.shape.traverse::<Face>().count(), 6);
+    }
+}
@@ -0,0 +1,156 @@
+use super::{path_to_cstring, read_file, ExchangeError, Format, ImportOutput, LengthUnit};
+use crate::Shape;
+use core::ffi::c_char;
+use cpp::cpp;
+use std::{
+    path::Path,
+    sync::{Mutex, PoisonError},
+};
+
+cpp! {{
+    #include <memory>
+
+    #include <Standard_Failure.hxx>
+
+    #include <TopoDS_Shape.hxx>
+
+    #include <TCollection_AsciiString.hxx>
+    #include <Interface_Static.hxx>
+
+    #include <STEPControl_Writer.hxx>
+
+    using namespace std;
+
+    // Restore the global writing parameters when leaving the scope
+    struct StepWriteParameters {
+        Standard_Integer schema;
+        TCollection_AsciiString unit;
+
+        StepWriteParameters()
+            : schema(Interface_Static::IVal("write.step.schema")),
+              unit(Interface_Static::CVal("write.step.unit")) {}
+
+        ~StepWriteParameters() {
+            Interface_Static::SetIVal("write.step.schema", schema);
+            Interface_Static::SetCVal("write.step.unit", unit.ToCString());
+        }
+    };
+}}
+
+/// The writer parameters are global, so concurrent writers must not interleave.
+static WRITE_PARAMETERS: Mutex<()> = Mutex::new(());
+
+enum_impls! {
+    /// STEP application protocol schema
+    StepSchema {
+        /// AP214 Committee Draft
+        Ap214Cd = 1,
+        /// AP214 Draft International Standard
+        Ap214Dis,
+        /// AP203 Configuration controlled 3D design
+        Ap203,
+        /// AP214 International Standard
+        Ap214Is,
+        /// AP242 Draft International Standard
+        Ap242Dis,
+    }
+}
+
+/// STEP writing options
+#[derive(Clone, Copy, Debug)]
+pub struct StepOptions {
+    /// The schema of file
+    pub schema: StepSchema,
+    /// The length unit of file
+    pub unit: LengthUnit,
+}
+
+impl Default for StepOptions {
+    fn default() -> Self {
+        Self {
+            schema: StepSchema::Ap214Is,
+            unit: LengthUnit::default(),
+        }
+    }
+}
+
+impl Shape {
+    /// Read shape from STEP file
+    pub fn read_step(path: impl AsRef<Path>) -> Result<ImportOutput, ExchangeError> {
+        read_file(path.as_ref(), Format::Step)
+    }
+
+    /// Write shape to STEP file
+    pub fn write_step(
+        &self,
+        path: impl AsRef<Path>,
+        options: &StepOptions,
+    ) -> Result<(), ExchangeError> {
+        let path = path_to_cstring(path.as_ref())?;
+        let path = path.as_ptr();
+        let schema = options.schema;
+        let unit = options.unit.to_cstring();
+        let unit: *const c_char = unit.as_ptr();
+
+        let _lock = WRITE_PARAMETERS
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner);
+        let rc = unsafe {
+            cpp!([self as "const unique_ptr<TopoDS_Shape>*", path as "const char*", schema as "Standard_Integer", unit as "const char*"] -> u32 as "uint32_t" {
+                try {
+                    STEPControl_Writer writer;
+                    StepWriteParameters parameters;
+                    Interface_Static::SetIVal("write.step.schema", schema);
+                    Interface_Static::SetCVal("write.step.unit", unit);
+                    writer.Model(Standard_True);
+                    try {
+                        if (writer.Transfer(**self, STEPControl_AsIs) != IFSelect_RetDone) {
+                            return 1;
+                        }
+                    } catch (const Standard_Failure&) {
+                        return 1;
+                    }
+                    if (writer.Write(path) != IFSelect_RetDone) {
+                        return 2;
+                    }
+                    return 0;
+                } catch (const Standard_Failure&) {
+                    return 2;
+                }
+            })
+        };
+
+        match rc {
+            0 => Ok(()),
+            1 => Err(ExchangeError::TransferFailed),
+            _ => Err(ExchangeError::WriteFailed),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::fixtures::*;
+    use crate::Solid;
+
+    #[test]
+    fn step() {
+        let s1 = Solid::box_(axis([0.0, 0.0, 0.0]), 1.0, 2.0, 3.0).unwrap();
+        let path = TempFile::new("step.step");
+
+        s1.write_step(&path, &StepOptions::default()).unwrap();
+        let r1 = Shape::read_step(&path).unwrap();
+        let r2 = Shape::read_step(path.with_extension("none"));
+
+        assert_eq!(r1.roots, 1);
+        assert_eq!(r1.shape.traverse::<Solid>().count(), 1);
+        assert_eq!(r2.err(), Some(ExchangeError::ReadFailed));
+
+        let garbage = TempFile::new("garbage.step");
+        std::fs::write(&garbage, "ISO-10303-21;\nHEADER;\n#1=(((;\nENDSEC;\n").unwrap();
+        let r3 = Shape::read_step(&garbage);
+
+        assert!(r3.is_err());
+    }
+}
@@ -0,0 +1,148 @@
+use super::{path_to_cstring, ExchangeError};
+use crate::{Angle, AngleUnits, Shape, TypedShape};
+use cpp::cpp;
+use std::path::Path;
+
+cpp! {{
+    #include <memory>
+
+    #include <Standard_Failure.hxx>
+
+    #include <TopoDS.hxx>
+    #include <TopoDS_Shape.hxx>
+    #include <TopoDS_Solid.hxx>
+
+    #include <BRep_Tool.hxx>
+    #include <BRepLib.hxx>
+    #include <BRepBuilderAPI_MakeSolid.hxx>
+    #include <BRepMesh_IncrementalMesh.hxx>
+
+    #include <StlAPI_Reader.hxx>
+    #include <StlAPI_Writer.hxx>
+
+    using namespace std;
+}}
+
+/// STL writing options
+#[derive(Clone, Copy, Debug)]
+pub struct StlOptions {
+    /// The maximum distance between triangles and surface
+    pub linear_deflection: f64,
+    /// The maximum angle between normals of adjacent triangles
+    pub angular_deflection: Angle,
+    /// Write text file instead of binary one
+    pub ascii: bool,
+}
+
+impl Default for StlOptions {
+    fn default() -> Self {
+        Self {
+            linear_deflection: 0.1,
+            angular_deflection: Angle::new(0.5, AngleUnits::Rad),
+            ascii: false,
+        }
+    }
+}
+
+impl Shape {
+    /// Read triangulated shape from STL file
+    ///
+    /// The triangles are sewn into shell which is converted into solid when it is closed.
+    pub fn read_stl(path: impl AsRef<Path>) -> Result<TypedShape, ExchangeError> {
+        let path = path_to_cstring(path.as_ref())?;
+        let path = path.as_ptr();
+
+        let mut shape = Shape::default();
+        let r = &mut shape;
+
+        let ok = unsafe {
+            cpp!([path as "const char*", r as "unique_ptr<TopoDS_Shape>*"] -> bool as "Standard_Boolean" {
+                try {
+                    StlAPI_Reader reader;
+                    TopoDS_Shape shape;
+                    if (!reader.Read(shape, path)) {
+                        return Standard_False;
+                    }
+                    if (!shape.IsNull() && shape.ShapeType() == TopAbs_SHELL && BRep_Tool::IsClosed(shape)) {
+                        BRepBuilderAPI_MakeSolid b(TopoDS::Shell(shape));
+                        if (b.IsDone()) {
+                            TopoDS_Solid solid = b.Solid();
+                            BRepLib::OrientClosedSolid(solid);
+                            shape = solid;
+                        }
+                    }
+                    *r = unique_ptr<TopoDS_Shape>(new TopoDS_Shape(shape));
+                    return Standard_True;
+                } catch (const Standard_Failure&) {
+                    return Standard_False;
+                }
+            })
+        };
+
+        if ok {
+            shape.try_into().map_err(|_| ExchangeError::NoShapes)
+        } else {
+            Err(ExchangeError::ReadFailed)
+        }
+    }
+
+    /// Triangulate shape and write it to STL file
+    pub fn write_stl(
+        &self,
+        path: impl AsRef<Path>,
+        options: &StlOptions,
+    ) -> Result<(), ExchangeError> {
+        let path = path_to_cstring(path.as_ref())?;
+        let path = path.as_ptr();
+        let linear = options.linear_deflection;
+        let angular = *options.angular_deflection.to(AngleUnits::Rad).raw();
+        let ascii = options.ascii;
+
+        let rc = unsafe {
+            cpp!([self as "const unique_ptr<TopoDS_Shape>*", path as "const char*", linear as "Standard_Real", angular as "Standard_Real", ascii as "Standard_Boolean"] -> u32 as "uint32_t" {
+                try {
+                    BRepMesh_IncrementalMesh mesh(**self, linear, Standard_False, angular, Standard_True);
+                    if (!mesh.IsDone()) {
+                        return 1;
+                    }
+                } catch (const Standard_Failure&) {
+                    return 1;
+                }
+                try {
+                    StlAPI_Writer writer;
+                    writer.ASCIIMode() = ascii;
+                    if (!writer.Write(**self, path)) {
+                        return 2;
+                    }
+                    return 0;
+                } catch (const Standard_Failure&) {
+                    return 2;
+                }
+            })
+        };
+
+        match rc {
+            0 => Ok(()),
+            1 => Err(ExchangeError::TransferFailed),
+            _ => Err(ExchangeError::WriteFailed),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::fixtures::*;
+    use crate::Solid;
+
+    #[test]
+    fn stl() {
+        let s1 = Solid::box_(axis([0.0, 0.0, 0.0]), 1.0, 2.0, 3.0).unwrap();
+        let path = TempFile::new("stl.stl");
+
+        s1.write_stl(&path, &StlOptions::default()).unwrap();
+        let r1 = Shape::read_stl(&path).unwrap();
+
+        assert!(matches!(r1, TypedShape::Solid(_)));
+    }
+}
@@ -0,0 +1,66 @@
+//! The shapes and files which are commonly used in tests
+
+use crate::{Axis3, Edge, Solid, Vertex, Wire};
+use std::path::{Path, PathBuf};
+
+/// Make axis in the given origin which is aligned with global axes
+pub fn axis(origin: [f64; 3]) -> Axis3 {
+    ((origin, [0.0, 0.0, 1.0]), [0.0, 1.0, 0.0], [1.0, 0.0, 0.0]).into()
+}
+
+/// Make cube with the corner in the given origin
+pub fn cube(origin: [f64; 3], size: f64) -> Solid {
+    Vertex::from(&origin)
+        .extrude(&[size, 0.0, 0.0])
+        .unwrap()
+        .extrude(&[0.0, size, 0.0])
+        .unwrap()
+        .extrude(&[0.0, 0.0, size])
+        .unwrap()
+}
+
+/// Make wire which connects the given points by segments
+pub fn polyline(points: &[[f64; 3]]) -> Wire {
+    let edges = points
+        .windows(2)
+        .map(|pair| Edge::try_from((&pair[0], &pair[1])).unwrap())
+        .collect::<Vec<_>>();
+    Wire::from_edges(&edges).unwrap()
+}
+
+/// Make horizontal square around Z axis
+pub fn square(z: f64, half_size: f64) -> Wire {
+    let s = half_size;
+    polyline(&[[-s, -s, z], [s, -s, z], [s, s, z], [-s, s, z], [-s, -s, z]])
+}
+
+/// Temporary file which is removed when dropped
+pub struct TempFile(PathBuf);
+
+impl TempFile {
+    /// Make path in temporary directory which is unique to the process and test
+    pub fn new(name: &str) -> Self {
+        let name = format!("perfector-{}-{}", std::process::id(), name);
+        Self(std::env::temp_dir().join(name))
+    }
+}
+
+impl AsRef<Path> for TempFile {
+    fn as_ref(&self) -> &Path {
+        &self.0
+    }
+}
+
+impl core::ops::Deref for TempFile {
+    type Target = Path;
+
+    fn deref(&self) -> &Path {
+        &self.0
+    }
+}
+
+impl Drop for TempFile {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_file(&self.0);
+    }
+}
@@ -53,9 +53,15 @@ macro_rules! enum_impls {
 }
 
 mod brep;
+mod exchange;
 mod math;
+mod message;
+
+#[cfg(test)]
+mod fixtures;
 
 pub use brep::*;
+pub use exchange::*;
 pub use math::*;
 
 use core::{marker::PhantomData, mem::ManuallyDrop};
@@ -0,0 +1,46 @@
+use cpp::{cpp, cpp_class};
+use std::ffi::CStr;
+
+cpp! {{
+    #include <memory>
+    #include <string>
+    #include <vector>
+
+    using namespace std;
+}}
+
+/// The list of messages collected on C++ side
+cpp_class!(pub(crate) unsafe struct Messages as "unique_ptr<vector<string>>");
+
+impl Messages {
+    pub(crate) fn new() -> Self {
+        unsafe {
+            cpp!([] -> Messages as "unique_ptr<vector<string>>" {
+                return unique_ptr<vector<string>>(new vector<string>());
+            })
+        }
+    }
+
+    fn len(&self) -> usize {
+        unsafe {
+            cpp!([self as "const unique_ptr<vector<string>>*"] -> usize as "size_t" {
+                return (*self)->size();
+            })
+        }
+    }
+
+    fn get(&self, index: usize) -> String {
+        let ptr = unsafe {
+            cpp!([self as "const unique_ptr<vector<string>>*", index as "size_t"] -> *const core::ffi::c_char as "const char*" {
+                return (**self)[index].c_str();
+            })
+        };
+        unsafe { CStr::from_ptr(ptr) }
+            .to_string_lossy()
+            .into_owned()
+    }
+
+    pub(crate) fn to_vec(&self) -> Vec<String> {
+        (0..self.len()).map(|index| self.get(index)).collect()
+    }
+}